@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// A single part's answer. Every [`Puzzle::Output`](crate::puzzles::Puzzle::Output)
+/// converts into one, so answers of different days can be stored and
+/// compared together.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    BigSigned(i128),
+    BigUnsigned(u128),
+    Text(String),
+}
+
+impl Answer {
+    /// Name of the type the answer is stored as.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "i64",
+            Answer::Unsigned(_) => "u64",
+            Answer::BigSigned(_) => "i128",
+            Answer::BigUnsigned(_) => "u128",
+            Answer::Text(_) => "string",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::BigSigned(n) => write!(f, "{}", n),
+            Answer::BigUnsigned(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident as $repr:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer::$variant(value as $repr)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Signed as i64: i8, i16, i32, i64, isize);
+impl_from!(Unsigned as u64: u8, u16, u32, u64, usize);
+impl_from!(BigSigned as i128: i128);
+impl_from!(BigUnsigned as u128: u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(-3i32), Answer::Signed(-3));
+        assert_eq!(Answer::from(43usize), Answer::Unsigned(43));
+        assert_eq!(
+            Answer::from(3121910778619u128),
+            Answer::BigUnsigned(3121910778619)
+        );
+        assert_eq!(Answer::from("ABC"), Answer::Text("ABC".to_string()));
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(3121910778619u128).to_string(), "3121910778619");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+        assert_eq!(Answer::from(7i64).kind(), "i64");
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::BTreeMap, error::Error, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::files::read_optional;
+
+/// Known-good answers, checked by `aoc2025 verify` and extended by
+/// `verify --record`. Kept per event year, beside that year's cached inputs;
+/// see [`crate::config::Profile::answers_path`].
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// An answer as written in [`ANSWERS_FILE`]: small numbers may be left
+/// unquoted, anything else is a string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<Value> for String {
+    fn from(value: Value) -> String {
+        match value {
+            Value::Integer(n) => n.to_string(),
+            Value::Text(s) => s,
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayEntry {
+    part1: Option<Value>,
+    part2: Option<Value>,
+}
+
+/// How a freshly computed answer compares with the stored one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome<'a> {
+    Matching,
+    Changed { expected: &'a str },
+    Unknown,
+}
+
+/// The answers store, keyed by day and part. On disk it is a table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = 3
+/// part2 = "6"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<i32, [Option<String>; 2]>,
+}
+
+impl Answers {
+    /// Loads the store at `path`; a missing file is an empty store.
+    pub fn load(path: &Path) -> Result<Answers, Box<dyn Error>> {
+        match read_optional(path)? {
+            Some(text) => Answers::parse(&text)
+                .map_err(|e| format!("invalid {}: {}", path.display(), e).into()),
+            None => Ok(Answers::default()),
+        }
+    }
+
+    fn parse(text: &str) -> Result<Answers, Box<dyn Error>> {
+        let tables: BTreeMap<String, DayEntry> = toml::from_str(text)?;
+
+        let mut days = BTreeMap::new();
+        for (key, entry) in tables {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a table named like [day1], found [{}]", key))?;
+            days.insert(
+                day,
+                [entry.part1.map(Into::into), entry.part2.map(Into::into)],
+            );
+        }
+
+        Ok(Answers { days })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_toml())?;
+        Ok(())
+    }
+
+    /// Writes the store with days in numeric order (a TOML serializer
+    /// would sort `day10` before `day2`).
+    fn to_toml(&self) -> String {
+        let mut tables = vec![];
+        for (day, parts) in &self.days {
+            let mut table = format!("[day{}]\n", day);
+            for (idx, answer) in parts.iter().enumerate() {
+                if let Some(answer) = answer {
+                    let value = toml::Value::String(answer.clone());
+                    table += &format!("part{} = {}\n", idx + 1, value);
+                }
+            }
+            tables.push(table);
+        }
+
+        tables.join("\n")
+    }
+
+    pub fn get(&self, day: i32, part: u8) -> Option<&str> {
+        self.days.get(&day)?[part as usize - 1].as_deref()
+    }
+
+    pub fn insert(&mut self, day: i32, part: u8, answer: String) {
+        self.days.entry(day).or_default()[part as usize - 1] = Some(answer);
+    }
+
+    pub fn check(&self, day: i32, part: u8, answer: &str) -> Outcome<'_> {
+        match self.get(day, part) {
+            None => Outcome::Unknown,
+            Some(expected) if expected == answer => Outcome::Matching,
+            Some(expected) => Outcome::Changed { expected },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_parse() {
+        let answers = Answers::parse(
+            "[day1]\npart1 = 3\npart2 = \"6\"\n\n[day11]\npart2 = \"290219757077250\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("3"));
+        assert_eq!(answers.get(1, 2), Some("6"));
+        assert_eq!(answers.get(11, 1), None);
+        assert_eq!(answers.get(11, 2), Some("290219757077250"));
+        assert_eq!(answers.get(2, 1), None);
+
+        assert!(Answers::parse("[one]\npart1 = 3\n").is_err());
+        assert!(Answers::parse("[day1]\npart3 = 3\n").is_err());
+    }
+
+    #[test]
+    fn test_answers_check() {
+        let mut answers = Answers::default();
+        answers.insert(4, 1, "13".to_string());
+
+        assert_eq!(answers.check(4, 1, "13"), Outcome::Matching);
+        assert_eq!(
+            answers.check(4, 1, "14"),
+            Outcome::Changed { expected: "13" }
+        );
+        assert_eq!(answers.check(4, 2, "43"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.insert(10, 2, "1694".to_string());
+        answers.insert(2, 1, "1227775554".to_string());
+        answers.insert(2, 2, "4174379265".to_string());
+
+        let text = answers.to_toml();
+        assert_eq!(
+            text,
+            "[day2]\npart1 = \"1227775554\"\npart2 = \"4174379265\"\n\n[day10]\npart2 = \"1694\"\n"
+        );
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+    }
+}
@@ -0,0 +1,243 @@
+use std::{error::Error, fs, path::Path, time::Duration};
+
+use crate::{files::read_optional, runner::Timing};
+
+/// Where `aoc2025 bench` keeps its results. The previous results in it are
+/// the baseline the next run is compared against.
+pub const BENCH_FILE: &str = "bench_output.txt";
+
+const HEADER: &str = "day\tstage\truns\tmin_ns\tmedian_ns\tmax_ns";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Stage> {
+        [Stage::Parse, Stage::Part1, Stage::Part2]
+            .into_iter()
+            .find(|stage| stage.as_str() == s)
+    }
+}
+
+/// The timing of one stage of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: i32,
+    pub stage: Stage,
+    pub timing: Timing,
+}
+
+/// A measurement next to the baseline's for the same day and stage.
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub measurement: Measurement,
+    pub baseline: Option<Duration>,
+    /// Relative change of the median against the baseline, in percent.
+    pub change: Option<f64>,
+    pub regressed: bool,
+}
+
+/// One line per measurement, tab-separated, with times in nanoseconds.
+pub fn to_tsv(measurements: &[Measurement]) -> String {
+    let mut text = HEADER.to_string() + "\n";
+    for Measurement { day, stage, timing } in measurements {
+        text += &format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            day,
+            stage.as_str(),
+            timing.runs,
+            timing.min.as_nanos(),
+            timing.median.as_nanos(),
+            timing.max.as_nanos()
+        );
+    }
+    text
+}
+
+pub fn from_tsv(text: &str) -> Result<Vec<Measurement>, String> {
+    let mut lines = text.lines();
+    if lines.next() != Some(HEADER) {
+        return Err("not a bench results file".to_string());
+    }
+
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(idx, line)| {
+            let invalid = || format!("line {} is invalid: {:?}", idx + 2, line);
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, stage, runs, min, median, max] = fields[..] else {
+                return Err(invalid());
+            };
+            let nanos = |field: &str| {
+                field
+                    .parse()
+                    .map(Duration::from_nanos)
+                    .map_err(|_| invalid())
+            };
+
+            Ok(Measurement {
+                day: day.parse().map_err(|_| invalid())?,
+                stage: Stage::parse(stage).ok_or_else(invalid)?,
+                timing: Timing {
+                    runs: runs.parse().map_err(|_| invalid())?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    max: nanos(max)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// The measurements in `path`, or none if it does not exist yet.
+pub fn load(path: &Path) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    match read_optional(path)? {
+        Some(text) => {
+            from_tsv(&text).map_err(|e| format!("invalid {}: {}", path.display(), e).into())
+        }
+        None => Ok(vec![]),
+    }
+}
+
+pub fn save(path: &Path, measurements: &[Measurement]) -> Result<(), Box<dyn Error>> {
+    fs::write(path, to_tsv(measurements))
+        .map_err(|e| format!("cannot write {}: {}", path.display(), e).into())
+}
+
+/// `saved` with the days in `measurements` replaced by their new
+/// measurements, so that benchmarking some days keeps the others' results.
+pub fn merge(saved: &[Measurement], measurements: &[Measurement]) -> Vec<Measurement> {
+    let mut merged: Vec<Measurement> = saved
+        .iter()
+        .filter(|old| !measurements.iter().any(|new| new.day == old.day))
+        .chain(measurements)
+        .copied()
+        .collect();
+    merged.sort_by_key(|measurement| (measurement.day, measurement.stage));
+    merged
+}
+
+/// Parses a `--threshold`: a percentage, which must be finite and not
+/// negative.
+pub fn parse_threshold(s: &str) -> Result<f64, String> {
+    let threshold: f64 = s.parse().map_err(|_| format!("{:?} is not a number", s))?;
+    if threshold.is_finite() && threshold >= 0.0 {
+        Ok(threshold)
+    } else {
+        Err(format!(
+            "{} is not a percentage: it must be finite and at least 0",
+            s
+        ))
+    }
+}
+
+/// Compares medians with the baseline. A stage regressed if its median grew
+/// by more than `threshold` percent.
+pub fn compare(
+    baseline: &[Measurement],
+    measurements: &[Measurement],
+    threshold: f64,
+) -> Vec<Comparison> {
+    measurements
+        .iter()
+        .map(|measurement| {
+            let baseline = baseline
+                .iter()
+                .find(|old| old.day == measurement.day && old.stage == measurement.stage)
+                .map(|old| old.timing.median)
+                .filter(|median| !median.is_zero());
+            let change = baseline.map(|baseline| {
+                (measurement.timing.median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+            });
+
+            Comparison {
+                measurement: *measurement,
+                baseline,
+                change,
+                regressed: change.is_some_and(|change| change > threshold),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: i32, stage: Stage, median_us: u64) -> Measurement {
+        let median = Duration::from_micros(median_us);
+        Measurement {
+            day,
+            stage,
+            timing: Timing {
+                runs: 3,
+                min: median / 2,
+                median,
+                max: median * 2,
+            },
+        }
+    }
+
+    #[test]
+    fn test_bench_tsv_roundtrip() {
+        let measurements = vec![
+            measurement(1, Stage::Parse, 10),
+            measurement(1, Stage::Part1, 250),
+            measurement(11, Stage::Part2, 1),
+        ];
+        let text = to_tsv(&measurements);
+
+        assert!(text.starts_with(
+            "day\tstage\truns\tmin_ns\tmedian_ns\tmax_ns\n1\tparse\t3\t5000\t10000\t20000\n"
+        ));
+        assert_eq!(from_tsv(&text).unwrap(), measurements);
+        assert!(from_tsv("1\tparse\n").is_err());
+        assert!(from_tsv(&format!("{}\n1\tsolve\t1\t1\t1\t1\n", HEADER)).is_err());
+    }
+
+    #[test]
+    fn test_bench_threshold() {
+        assert_eq!(parse_threshold("10"), Ok(10.0));
+        assert_eq!(parse_threshold("0"), Ok(0.0));
+        assert_eq!(parse_threshold("2.5"), Ok(2.5));
+        for invalid in ["-5", "NaN", "inf", "ten", ""] {
+            assert!(parse_threshold(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_bench_compare() {
+        let baseline = vec![
+            measurement(1, Stage::Part1, 100),
+            measurement(1, Stage::Part2, 100),
+        ];
+        let current = vec![
+            measurement(1, Stage::Part1, 105),
+            measurement(1, Stage::Part2, 150),
+            measurement(2, Stage::Part1, 100),
+        ];
+        let comparisons = compare(&baseline, &current, 10.0);
+
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert_eq!(comparisons[1].change.map(f64::round), Some(50.0));
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].regressed);
+
+        let merged = merge(&current, &[measurement(1, Stage::Parse, 7)]);
+        assert_eq!(merged, [measurement(1, Stage::Parse, 7), current[2]]);
+    }
+}
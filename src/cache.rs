@@ -0,0 +1,314 @@
+use std::{error::Error, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::files::read_optional;
+
+/// Where downloaded inputs are kept, one `dayN.txt` per day.
+pub const INPUT_DIR: &str = "puzzle_inputs";
+
+/// Recorded next to each downloaded input as `dayN.meta.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Seconds since the Unix epoch.
+    pub fetched_at: u64,
+    pub bytes: usize,
+    pub sha256: String,
+    /// HTTP status of the response the input came from.
+    pub status: u16,
+}
+
+impl Metadata {
+    pub fn new(input: &str, status: u16, fetched_at: u64) -> Metadata {
+        Metadata {
+            fetched_at,
+            bytes: input.len(),
+            sha256: sha256(input),
+            status,
+        }
+    }
+
+    /// Why `input` is not the file this metadata was recorded for, if it
+    /// is not.
+    fn mismatch(&self, input: &str) -> Option<String> {
+        if input.len() != self.bytes {
+            Some(format!(
+                "it is {} bytes, but {} were downloaded",
+                input.len(),
+                self.bytes
+            ))
+        } else if sha256(input) != self.sha256 {
+            Some("its hash does not match the downloaded one".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+fn sha256(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Rejects responses that are obviously not a puzzle input: error pages and
+/// the site's notices.
+pub fn validate(input: &str) -> Result<(), String> {
+    let lower = input.trim_start().to_ascii_lowercase();
+
+    if input.trim().is_empty() {
+        Err("it is empty".to_string())
+    } else if lower.starts_with("<!doctype html") || lower.starts_with("<html") {
+        Err("it is an HTML page".to_string())
+    } else if input.contains("Puzzle inputs differ by user") {
+        Err("it is the site's log-in notice".to_string())
+    } else if input.contains("before it unlocks") {
+        Err("it is the site's not-unlocked-yet notice".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// One cached input, as shown by `aoc2025 cache list`.
+#[derive(Debug)]
+pub struct Entry {
+    pub day: i32,
+    pub bytes: usize,
+    /// `None` for inputs put in place by hand.
+    pub metadata: Option<Metadata>,
+    /// Why the input would be rejected, if it would be.
+    pub problem: Option<String>,
+}
+
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache { dir: dir.into() }
+    }
+
+    pub fn input_path(&self, day: i32) -> PathBuf {
+        self.dir.join(format!("day{}.txt", day))
+    }
+
+    fn metadata_path(&self, day: i32) -> PathBuf {
+        self.dir.join(format!("day{}.meta.json", day))
+    }
+
+    fn metadata(&self, day: i32) -> Result<Option<Metadata>, Box<dyn Error>> {
+        let path = self.metadata_path(day);
+        match read_optional(&path)? {
+            Some(text) => {
+                Ok(Some(serde_json::from_str(&text).map_err(|e| {
+                    format!("invalid {}: {}", path.display(), e)
+                })?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Why the cached `input` for `day` should not be used, if it should not.
+    fn problem(&self, input: &str, metadata: Option<&Metadata>) -> Option<String> {
+        validate(input)
+            .err()
+            .or_else(|| metadata.and_then(|metadata| metadata.mismatch(input)))
+    }
+
+    /// The cached input for `day`, if there is one. An input that fails
+    /// validation or no longer matches its metadata is an error rather than
+    /// being served.
+    pub fn read(&self, day: i32) -> Result<Option<String>, Box<dyn Error>> {
+        let path = self.input_path(day);
+        let Some(input) = read_optional(&path)? else {
+            return Ok(None);
+        };
+
+        match self.problem(&input, self.metadata(day)?.as_ref()) {
+            Some(problem) => Err(format!(
+                "cached input {} is unusable: {}; run `aoc2025 cache refresh {}`",
+                path.display(),
+                problem,
+                day
+            )
+            .into()),
+            None => Ok(Some(input)),
+        }
+    }
+
+    /// Stores a downloaded input with its metadata, unless it fails
+    /// [`validate`].
+    pub fn write(&self, day: i32, input: &str, metadata: &Metadata) -> Result<(), Box<dyn Error>> {
+        validate(input).map_err(|problem| {
+            format!("downloaded input for day {} is unusable: {}", day, problem)
+        })?;
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.input_path(day), input)?;
+        fs::write(
+            self.metadata_path(day),
+            serde_json::to_string_pretty(metadata)? + "\n",
+        )?;
+
+        Ok(())
+    }
+
+    /// Removes the input for `day` and its metadata. Returns whether there
+    /// was an input to remove.
+    pub fn delete(&self, day: i32) -> Result<bool, Box<dyn Error>> {
+        let mut removed = false;
+        for path in [self.input_path(day), self.metadata_path(day)] {
+            match fs::remove_file(&path) {
+                Ok(()) => removed |= path == self.input_path(day),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(format!("cannot delete {}: {}", path.display(), e).into()),
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// The listing for the file `name` in the cache, which holds the input
+    /// for `day`. A file that cannot be served is listed with the reason.
+    fn entry(&self, day: i32, name: &str) -> Entry {
+        let path = self.dir.join(name);
+        let bytes = fs::read(&path);
+        let mut entry = Entry {
+            day,
+            bytes: bytes.as_ref().map_or(0, Vec::len),
+            metadata: None,
+            problem: None,
+        };
+
+        entry.problem = match bytes {
+            Err(e) => Some(format!("cannot read it: {}", e)),
+            Ok(_) if path != self.input_path(day) => {
+                Some(format!("it is never read: rename it to day{}.txt", day))
+            }
+            Ok(bytes) => match (String::from_utf8(bytes), self.metadata(day)) {
+                (Err(_), _) => Some("it is not UTF-8 text".to_string()),
+                (_, Err(e)) => Some(e.to_string()),
+                (Ok(input), Ok(metadata)) => {
+                    entry.metadata = metadata;
+                    self.problem(&input, entry.metadata.as_ref())
+                }
+            },
+        };
+
+        entry
+    }
+
+    /// Every cached input, in day order, including files that could not be
+    /// served.
+    pub fn list(&self) -> Result<Vec<Entry>, Box<dyn Error>> {
+        let dir = match fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(format!("cannot read {}: {}", self.dir.display(), e).into()),
+        };
+
+        let mut entries = vec![];
+        for file in dir {
+            let name = file?.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            let Some(day) = name
+                .strip_prefix("day")
+                .and_then(|name| name.strip_suffix(".txt"))
+                .and_then(|day| day.parse().ok())
+            else {
+                continue;
+            };
+
+            entries.push(self.entry(day, name));
+        }
+
+        entries.sort_by_key(|entry| entry.day);
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> InputCache {
+        let dir =
+            std::env::temp_dir().join(format!("aoc2025-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        InputCache::new(dir)
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("1 2\n3 4\n").is_ok());
+        assert!(validate("\n").is_err());
+        assert!(validate("<!DOCTYPE html>\n<html lang=\"en-us\">").is_err());
+        assert!(validate(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        )
+        .is_err());
+        assert!(
+            validate("Please don't repeatedly request this endpoint before it unlocks!").is_err()
+        );
+    }
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let cache = temp_cache("roundtrip");
+        let input = "L68\nL30\n";
+
+        assert_eq!(cache.read(1).unwrap(), None);
+        cache
+            .write(1, input, &Metadata::new(input, 200, 42))
+            .unwrap();
+        assert_eq!(cache.read(1).unwrap().as_deref(), Some(input));
+
+        let entries = cache.list().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!((entries[0].day, entries[0].bytes), (1, 8));
+        assert_eq!(entries[0].metadata.as_ref().map(|m| m.fetched_at), Some(42));
+        assert_eq!(entries[0].problem, None);
+
+        assert!(cache.delete(1).unwrap());
+        assert!(!cache.delete(1).unwrap());
+        assert_eq!(cache.read(1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_cache_rejects_bad_inputs() {
+        let cache = temp_cache("reject");
+        let page = "<html><body>500 Internal Server Error</body></html>";
+
+        assert!(cache.write(2, page, &Metadata::new(page, 200, 0)).is_err());
+        assert_eq!(cache.read(2).unwrap(), None);
+
+        let input = "11-22,95-115\n";
+        cache
+            .write(2, input, &Metadata::new(input, 200, 0))
+            .unwrap();
+        fs::write(cache.input_path(2), "11-22,95-1").unwrap();
+        let err = cache.read(2).unwrap_err().to_string();
+        assert!(err.contains("10 bytes, but 13 were downloaded"), "{}", err);
+
+        fs::write(cache.input_path(2), page).unwrap();
+        assert!(cache.list().unwrap()[0].problem.is_some());
+
+        fs::write(cache.input_path(2), [0xff, 0xfe]).unwrap();
+        fs::write(cache.dir.join("day03.txt"), input).unwrap();
+        let entries = cache.list().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].problem.as_deref(), Some("it is not UTF-8 text"));
+        assert_eq!(entries[1].day, 3);
+        assert_eq!(
+            entries[1].problem.as_deref(),
+            Some("it is never read: rename it to day3.txt")
+        );
+
+        fs::remove_dir_all(&cache.dir).unwrap();
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    answers::ANSWERS_FILE, cache::INPUT_DIR, files::read_optional, submit::SUBMISSIONS_FILE,
+};
+
+/// Project settings file, looked up in the current directory.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: i32 = 2025;
+
+/// The first Advent of Code event.
+pub const FIRST_YEAR: i32 = 2015;
+
+/// How the default account is labelled in output, so no profile may take
+/// this name.
+const DEFAULT_PROFILE: &str = "default";
+
+fn check_year(year: i32) -> Result<i32, String> {
+    if year >= FIRST_YEAR {
+        Ok(year)
+    } else {
+        Err(format!(
+            "there is no {} event: Advent of Code started in {}",
+            year, FIRST_YEAR
+        ))
+    }
+}
+
+/// Where puzzles are fetched from. Each setting is taken from, in order of
+/// precedence: a command-line flag, the `AOC_BASE_URL` / `AOC_YEAR`
+/// environment variables, [`CONFIG_FILE`], and finally the defaults above.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub base_url: String,
+    pub year: i32,
+    /// The `session` key of [`CONFIG_FILE`]; see [`crate::session::Session::load`]
+    /// for where else a session can come from.
+    pub session: Option<String>,
+    /// The `[profiles.<name>]` tables of [`CONFIG_FILE`], by name.
+    pub profiles: Vec<Profile>,
+}
+
+/// An account whose session and inputs are kept apart from the others'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    /// `None` for the default account, which uses the usual session
+    /// sources and keeps its inputs in [`INPUT_DIR`].
+    pub name: Option<String>,
+    pub session: Option<String>,
+    pub input_dir: PathBuf,
+}
+
+impl Profile {
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Where the inputs and answers of the `year` event are kept, so that
+    /// switching years never mixes them up.
+    pub fn year_dir(&self, year: i32) -> PathBuf {
+        self.input_dir.join(year.to_string())
+    }
+
+    pub fn answers_path(&self, year: i32) -> PathBuf {
+        self.year_dir(year).join(ANSWERS_FILE)
+    }
+
+    pub fn submissions_path(&self) -> PathBuf {
+        self.input_dir.join(SUBMISSIONS_FILE)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    base_url: Option<String>,
+    year: Option<i32>,
+    session: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProfileFile {
+    session: Option<String>,
+    /// Defaults to `puzzle_inputs/<name>`.
+    input_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Reads [`CONFIG_FILE`] if there is one and applies the environment
+    /// on top of it.
+    pub fn load() -> Result<Config, Box<dyn Error>> {
+        let file = read_optional(Path::new(CONFIG_FILE))?;
+
+        Config::from_sources(file.as_deref(), |key| std::env::var(key).ok())
+    }
+
+    fn from_sources(
+        file: Option<&str>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, Box<dyn Error>> {
+        let file: ConfigFile = match file {
+            Some(text) => {
+                toml::from_str(text).map_err(|e| format!("invalid {}: {}", CONFIG_FILE, e))?
+            }
+            None => ConfigFile::default(),
+        };
+
+        let base_url = env("AOC_BASE_URL")
+            .or(file.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let year = match env("AOC_YEAR") {
+            Some(year) => year
+                .parse()
+                .map_err(|_| format!("AOC_YEAR is not a year: {:?}", year))
+                .and_then(|year| check_year(year).map_err(|e| format!("AOC_YEAR: {}", e)))?,
+            None => match file.year {
+                Some(year) => {
+                    check_year(year).map_err(|e| format!("invalid {}: {}", CONFIG_FILE, e))?
+                }
+                None => DEFAULT_YEAR,
+            },
+        };
+
+        let mut profiles = vec![];
+        for (name, profile) in file.profiles {
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                return Err(format!(
+                    "invalid {}: profile name {:?} may only use letters, digits, '-' and '_'",
+                    CONFIG_FILE, name
+                )
+                .into());
+            }
+            if name == DEFAULT_PROFILE {
+                return Err(format!(
+                    "invalid {}: profile name {:?} is taken by the default account",
+                    CONFIG_FILE, name
+                )
+                .into());
+            }
+            profiles.push(Profile {
+                input_dir: profile
+                    .input_dir
+                    .unwrap_or_else(|| Path::new(INPUT_DIR).join(&name)),
+                name: Some(name),
+                session: profile.session,
+            });
+        }
+
+        Ok(Config {
+            session: file.session,
+            profiles,
+            ..Config::new(base_url, year)
+        })
+    }
+
+    /// The profile called `name`, or the default account for `None`.
+    pub fn profile(&self, name: Option<&str>) -> Result<Profile, String> {
+        let Some(name) = name else {
+            return Ok(Profile {
+                name: None,
+                session: self.session.clone(),
+                input_dir: PathBuf::from(INPUT_DIR),
+            });
+        };
+
+        self.profiles
+            .iter()
+            .find(|profile| profile.name.as_deref() == Some(name))
+            .cloned()
+            .ok_or_else(|| format!("no profile named {:?} in {}", name, CONFIG_FILE))
+    }
+
+    /// The default account followed by every named profile.
+    pub fn all_profiles(&self) -> Vec<Profile> {
+        let mut profiles = vec![self
+            .profile(None)
+            .expect("the default profile always exists")];
+        profiles.extend(self.profiles.iter().cloned());
+        profiles
+    }
+
+    pub fn new(base_url: impl Into<String>, year: i32) -> Config {
+        Config {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            year,
+            session: None,
+            profiles: vec![],
+        }
+    }
+
+    pub fn with_base_url(self, base_url: impl Into<String>) -> Config {
+        Config {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            ..self
+        }
+    }
+
+    pub fn with_year(self, year: i32) -> Result<Config, String> {
+        Ok(Config {
+            year: check_year(year)?,
+            ..self
+        })
+    }
+
+    pub fn event_url(&self) -> String {
+        format!("{}/{}", self.base_url, self.year)
+    }
+
+    pub fn puzzle_url(&self, day: i32) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day)
+    }
+
+    pub fn input_url(&self, day: i32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, self.year, day)
+    }
+
+    pub fn answer_url(&self, day: i32) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, self.year, day)
+    }
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config::new(DEFAULT_BASE_URL, DEFAULT_YEAR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config = Config::from_sources(None, no_env).unwrap();
+
+        assert_eq!(config, Config::default());
+        assert_eq!(
+            config.input_url(3),
+            "https://adventofcode.com/2025/day/3/input"
+        );
+    }
+
+    #[test]
+    fn test_config_precedence() {
+        let file = "base_url = \"http://localhost:8080/\"\nyear = 2024\nsession = \"abc\"\n";
+
+        let config = Config::from_sources(Some(file), no_env).unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(
+            config.input_url(1),
+            "http://localhost:8080/2024/day/1/input"
+        );
+
+        let config = Config::from_sources(Some(file), |key| match key {
+            "AOC_YEAR" => Some("2026".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(
+            (config.base_url.as_str(), config.year),
+            ("http://localhost:8080", 2026)
+        );
+        assert_eq!(
+            config.answer_url(5),
+            "http://localhost:8080/2026/day/5/answer"
+        );
+
+        assert_eq!(
+            config
+                .clone()
+                .with_base_url("http://mock/")
+                .with_year(2015)
+                .unwrap(),
+            Config {
+                session: Some("abc".to_string()),
+                ..Config::new("http://mock", 2015)
+            }
+        );
+        assert!(config.with_year(2014).is_err());
+        assert!(Config::from_sources(Some("year = 1969\n"), no_env).is_err());
+        assert!(Config::from_sources(None, |_| Some("2014".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_config_profiles() {
+        let file = "session = \"aa\"\n\n[profiles.bob]\nsession = \"bb\"\n\n[profiles.alice]\ninput_dir = \"/tmp/alice\"\n";
+        let config = Config::from_sources(Some(file), no_env).unwrap();
+
+        let labels: Vec<_> = config
+            .all_profiles()
+            .iter()
+            .map(|p| p.label().to_string())
+            .collect();
+        assert_eq!(labels, ["default", "alice", "bob"]);
+
+        let default = config.profile(None).unwrap();
+        assert_eq!(default.session.as_deref(), Some("aa"));
+        assert_eq!(
+            default.answers_path(2024),
+            Path::new("puzzle_inputs/2024/answers.toml")
+        );
+
+        let bob = config.profile(Some("bob")).unwrap();
+        assert_eq!(bob.session.as_deref(), Some("bb"));
+        assert_eq!(bob.input_dir, Path::new("puzzle_inputs/bob"));
+        assert_eq!(bob.year_dir(2025), Path::new("puzzle_inputs/bob/2025"));
+        assert_eq!(
+            config.profile(Some("alice")).unwrap().input_dir,
+            Path::new("/tmp/alice")
+        );
+        assert!(config.profile(Some("carol")).is_err());
+    }
+
+    #[test]
+    fn test_config_errors() {
+        assert!(Config::from_sources(Some("[profiles.\"../x\"]"), no_env).is_err());
+        let err = Config::from_sources(Some("[profiles.default]"), no_env).unwrap_err();
+        assert!(
+            err.to_string().contains("taken by the default account"),
+            "{}",
+            err
+        );
+        assert!(Config::from_sources(Some("year = \"soon\""), no_env).is_err());
+        assert!(Config::from_sources(None, |_| Some("next".to_string())).is_err());
+    }
+}
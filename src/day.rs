@@ -0,0 +1,89 @@
+use std::{fmt, str::FromStr};
+
+/// No event has had more puzzles than this.
+pub const MAX_DAY: i32 = 25;
+
+/// How many puzzles the `year` event has: 25 up to 2024, 12 from 2025 on.
+pub fn puzzle_count(year: i32) -> i32 {
+    if year >= 2025 {
+        12
+    } else {
+        MAX_DAY
+    }
+}
+
+/// A day number from the command line, between 1 and [`MAX_DAY`]. Whether
+/// the configured year has that many puzzles is checked with
+/// [`Day::check_year`] once the year is known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day(i32);
+
+impl Day {
+    pub fn new(day: i32) -> Result<Day, String> {
+        if (1..=MAX_DAY).contains(&day) {
+            Ok(Day(day))
+        } else {
+            Err(format!("days run from 1 to {}", MAX_DAY))
+        }
+    }
+
+    pub fn get(self) -> i32 {
+        self.0
+    }
+
+    pub fn check_year(self, year: i32) -> Result<(), String> {
+        let count = puzzle_count(year);
+        if self.0 <= count {
+            Ok(())
+        } else {
+            Err(format!(
+                "day {} does not exist: {} has {} puzzles",
+                self.0, year, count
+            ))
+        }
+    }
+}
+
+impl FromStr for Day {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Day, String> {
+        let day = s
+            .parse()
+            .map_err(|_| format!("not a day number; days run from 1 to {}", MAX_DAY))?;
+        Day::new(day)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_parse() {
+        assert_eq!("7".parse::<Day>().unwrap().get(), 7);
+        assert_eq!("25".parse::<Day>().unwrap().to_string(), "25");
+        assert!("0".parse::<Day>().is_err());
+        assert!("-3".parse::<Day>().is_err());
+        assert!("99".parse::<Day>().is_err());
+        assert!("day3".parse::<Day>().is_err());
+    }
+
+    #[test]
+    fn test_day_check_year() {
+        let day = Day::new(13).unwrap();
+
+        assert!(day.check_year(2024).is_ok());
+        assert_eq!(
+            day.check_year(2025).unwrap_err(),
+            "day 13 does not exist: 2025 has 12 puzzles"
+        );
+        assert!(Day::new(12).unwrap().check_year(2025).is_ok());
+    }
+}
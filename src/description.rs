@@ -0,0 +1,96 @@
+use crate::html::{between, text};
+
+/// The example of one part, as scraped from the puzzle description.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PartExample {
+    /// The first `<pre><code>` block of the part, if it has one. Part 2
+    /// usually reuses the part 1 example and has none.
+    pub input: Option<String>,
+    /// The last `<code><em>` in the part, which is where the description
+    /// states the example's answer.
+    pub answer: Option<String>,
+}
+
+/// Examples found on a puzzle's description page. `part2` is only there
+/// once part 2 is unlocked.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    pub part1: Option<PartExample>,
+    pub part2: Option<PartExample>,
+}
+
+impl PuzzleExamples {
+    pub fn from_html(html: &str) -> PuzzleExamples {
+        let mut parts = html
+            .split("<article class=\"day-desc\">")
+            .skip(1)
+            .map(|article| {
+                let article = article.split("</article>").next().unwrap_or(article);
+                PartExample {
+                    input: between(article, "<pre><code>", "</code></pre>").map(text),
+                    answer: article
+                        .rfind("<code><em>")
+                        .and_then(|at| between(&article[at..], "<code><em>", "</em></code>"))
+                        .map(text),
+                }
+            });
+
+        PuzzleExamples {
+            part1: parts.next(),
+            part2: parts.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 4: Printing Department ---</h2>
+<p>For example:</p>
+<pre><code>..@@.
+@@<em>@</em>.&amp;
+</code></pre>
+<p>There are <code><em>13</em></code> rolls of paper, out of <code><em>14</em></code>.</p>
+<p>In this example, <code><em>13</em></code> are accessible.</p>
+</article>
+<p>Your puzzle answer was <code>1424</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Using the <code>&lt;same&gt;</code> example, <code><em>43</em></code> can be removed.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_examples_from_html() {
+        let examples = PuzzleExamples::from_html(PAGE);
+
+        assert_eq!(
+            examples.part1,
+            Some(PartExample {
+                input: Some("..@@.\n@@@.&\n".to_string()),
+                answer: Some("13".to_string()),
+            })
+        );
+        assert_eq!(
+            examples.part2,
+            Some(PartExample {
+                input: None,
+                answer: Some("43".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_examples_before_part2_unlocks() {
+        let page = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        let examples = PuzzleExamples::from_html(page);
+
+        assert!(examples.part1.is_some());
+        assert_eq!(examples.part2, None);
+        assert_eq!(
+            PuzzleExamples::from_html("<html></html>"),
+            PuzzleExamples::default()
+        );
+    }
+}
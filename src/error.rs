@@ -0,0 +1,143 @@
+use std::fmt;
+
+/// Where in the puzzle input an error was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    /// The whole offending line, without its line ending.
+    pub snippet: String,
+}
+
+impl Location {
+    /// Locates `offset` (a byte offset into `input`).
+    fn new(input: &str, offset: usize) -> Location {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+}
+
+/// Error raised while solving a puzzle. Errors coming from malformed input
+/// carry the [`Location`] of the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleError {
+    pub day: Option<i32>,
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl PuzzleError {
+    pub fn new(message: impl Into<String>) -> PuzzleError {
+        PuzzleError {
+            day: None,
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// An error about `at`, which must be a slice of `input`. If it is not,
+    /// the error is reported without a location.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> PuzzleError {
+        let start = input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize).wrapping_sub(start);
+
+        PuzzleError {
+            location: (offset <= input.len()).then(|| Location::new(input, offset)),
+            ..PuzzleError::new(message)
+        }
+    }
+
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> PuzzleError {
+        match err {
+            nom::Err::Incomplete(_) => {
+                PuzzleError::at(input, &input[input.len()..], "unexpected end of input")
+            }
+            nom::Err::Error(err) | nom::Err::Failure(err) => PuzzleError::at(
+                input,
+                err.input,
+                format!("unexpected input (expected {})", err.code.description()),
+            ),
+        }
+    }
+
+    pub fn with_day(self, day: i32) -> PuzzleError {
+        PuzzleError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}: ", day)?;
+        }
+
+        let Some(location) = &self.location else {
+            return f.write_str(&self.message);
+        };
+
+        let gutter = " ".repeat(location.line.to_string().len());
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            location.line, location.column, self.message
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", location.line, location.snippet)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(location.column - 1))
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "L68\nL30\nX48\n";
+
+    #[test]
+    fn test_error_location() {
+        let err = PuzzleError::at(INPUT, &INPUT[9..], "unexpected rotation");
+
+        assert_eq!(
+            err.location,
+            Some(Location {
+                line: 3,
+                column: 2,
+                snippet: "X48".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_outside_input_has_no_location() {
+        let elsewhere = String::from("X48");
+        let err = PuzzleError::at(INPUT, &elsewhere, "unexpected rotation");
+
+        assert_eq!(err.location, None);
+    }
+
+    #[test]
+    fn test_error_display() {
+        let err = PuzzleError::at(INPUT, &INPUT[8..], "unexpected rotation \"X\"").with_day(1);
+
+        assert_eq!(
+            err.to_string(),
+            "day 1: line 3, column 1: unexpected rotation \"X\"\n  |\n3 | X48\n  | ^"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use std::{error::Error, fs, io, path::Path};
+
+/// The contents of `path`, or `None` if there is no such file. Any other
+/// failure to read it is an error naming the file.
+pub fn read_optional(path: &Path) -> Result<Option<String>, Box<dyn Error>> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("cannot read {}: {}", path.display(), e).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_optional() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-files-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("file.txt");
+        assert_eq!(read_optional(&path).unwrap(), None);
+        fs::write(&path, "text").unwrap();
+        assert_eq!(read_optional(&path).unwrap().as_deref(), Some("text"));
+        fs::write(&path, [0xff]).unwrap();
+        assert!(read_optional(&path)
+            .unwrap_err()
+            .to_string()
+            .starts_with(&format!("cannot read {}: ", path.display())));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
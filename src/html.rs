@@ -0,0 +1,45 @@
+/// The text between the first `start` in `text` and the `end` after it.
+pub fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let from = text.find(start)? + start.len();
+    let len = text[from..].find(end)?;
+    Some(&text[from..from + len])
+}
+
+/// The plain text of an HTML fragment: tags dropped, entities decoded.
+pub fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    stripped
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_text() {
+        let html = "<p>Use <code>&lt;b&gt;</code> &amp; <em>that</em></p><p>end</p>";
+
+        assert_eq!(between(html, "<code>", "</code>"), Some("&lt;b&gt;"));
+        assert_eq!(
+            between(html, "<p>", "</p>").map(text).as_deref(),
+            Some("Use <b> & that")
+        );
+        assert_eq!(between(html, "<pre>", "</pre>"), None);
+        assert_eq!(between(html, "<em>", "<pre>"), None);
+    }
+}
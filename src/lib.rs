@@ -1 +1,19 @@
-pub mod puzzles;
\ No newline at end of file
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod config;
+pub mod day;
+pub mod description;
+pub mod error;
+pub mod files;
+pub mod html;
+pub mod puzzles;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod session;
+pub mod submit;
+pub mod unlock;
+pub mod watch;
@@ -1,236 +1,1237 @@
-use aoc2025::puzzles::*;
-use clap::{Parser, Subcommand};
+use aoc2025::{
+    answers::{Answers, Outcome},
+    bench::{self, Measurement, Stage},
+    cache::{InputCache, Metadata},
+    config::{Config, Profile, FIRST_YEAR},
+    day::Day,
+    description::PuzzleExamples,
+    error::PuzzleError,
+    puzzles,
+    report::{self, Record, Status},
+    runner,
+    scaffold::{self, NewDay},
+    session::{self, FetchError, Session},
+    submit::{self, Check, Submission, Submissions, Verdict},
+    unlock,
+    watch::{self, Watcher},
+};
+use clap::{error::ErrorKind, ArgGroup, CommandFactory, Parser, Subcommand, ValueEnum};
 use reqwest::{
     header::{HeaderMap, HeaderValue},
-    Client,
+    Client, RequestBuilder,
 };
 use std::io::prelude::*;
-use std::{error::Error, fs, path::Path};
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Site to download inputs from [default: $AOC_BASE_URL, aoc.toml or https://adventofcode.com]
+    #[arg(long, global = true, value_name = "URL")]
+    base_url: Option<String>,
+    /// Event year [default: $AOC_YEAR, aoc.toml or 2025]
+    #[arg(long, global = true, value_parser = clap::value_parser!(i32).range(FIRST_YEAR as i64..))]
+    year: Option<i32>,
+    /// Use the session and inputs of a `[profiles.<name>]` table of aoc.toml
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Tsv,
 }
 
 #[derive(Subcommand)]
 enum Commands {
-    Day { day: i32 },
-    New { day: i32 },
+    Day {
+        day: Day,
+        /// Only solve the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from a file instead of puzzle_inputs/<year>/ ("-" for stdin)
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Solve the puzzle's examples and compare against their stated answers
+        #[arg(long, conflicts_with = "input")]
+        example: bool,
+        /// If the puzzle has not unlocked yet, wait for it instead of giving up
+        #[arg(long, conflicts_with_all = ["input", "example"])]
+        wait: bool,
+        /// Show how long each part took
+        #[arg(long)]
+        time: bool,
+        /// Solve each part this many times and report min/median/max timings
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solve every profile's input and check it against that profile's answers
+        #[arg(long, conflicts_with_all = ["input", "example"])]
+        all_profiles: bool,
+    },
+    /// Solve several days in sequence and print a summary table
+    #[command(group(ArgGroup::new("selection").required(true).args(["all", "days"])))]
+    Run {
+        /// Run every registered day
+        #[arg(long)]
+        all: bool,
+        days: Vec<Day>,
+        /// Solve each part this many times and report min/median/max timings
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solve the cached inputs of every profile
+        #[arg(long)]
+        all_profiles: bool,
+    },
+    /// Solve one part and submit its answer to the puzzle site
+    Submit {
+        day: Day,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit even if the answer is beyond a known too-high/too-low answer
+        #[arg(long)]
+        force: bool,
+    },
+    /// Check answers on the real inputs against the profile's answers.toml
+    Verify {
+        /// Days to check (default: every registered day)
+        days: Vec<Day>,
+        /// Store answers that are not in the answers file yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and both parts of each day on its cached input, flagging regressions
+    Bench {
+        /// Days to benchmark (default: every registered day)
+        days: Vec<Day>,
+        /// Runs per stage; the median is compared
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+        /// Flag stages whose median is slower than the baseline by more than this many percent
+        #[arg(long, default_value_t = 10.0, value_parser = bench::parse_threshold)]
+        threshold: f64,
+        /// File to write the results to
+        #[arg(long, value_name = "PATH", default_value = bench::BENCH_FILE)]
+        output: PathBuf,
+        /// Results to compare against [default: the previous contents of the output file]
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// Write the results even if some stages regressed, making them the new baseline
+        #[arg(long)]
+        accept: bool,
+    },
+    /// Rebuild and rerun a day's examples and input whenever its source or input changes
+    Watch { day: Day },
+    /// Check that the session cookie is valid and show who it logs in as
+    Whoami,
+    /// List, re-download or delete cached puzzle inputs
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Create a day module, with the examples from the puzzle description
+    New {
+        day: Day,
+        /// Read the puzzle description from a saved HTML file instead of the site
+        #[arg(long, value_name = "PATH")]
+        html: Option<PathBuf>,
+        /// Template to start from: templates/<NAME>.rs.tmpl, or built in (default, grid, graph, nom)
+        #[arg(long, value_name = "NAME", default_value = scaffold::DEFAULT_TEMPLATE)]
+        template: String,
+        /// Answer type of the new day
+        #[arg(long, value_name = "TYPE", default_value = "i64", value_parser = scaffold::OUTPUT_TYPES)]
+        output: String,
+        /// Show which files would be created or modified without writing them
+        #[arg(long)]
+        dry_run: bool,
+        /// Regenerate the module if it exists, keeping its registration
+        #[arg(long)]
+        force: bool,
+    },
+    /// Add the part 2 example to a day module created by `new`
+    Examples {
+        day: Day,
+        /// Read the puzzle description from a saved HTML file instead of the site
+        #[arg(long, value_name = "PATH")]
+        html: Option<PathBuf>,
+    },
 }
 
-async fn get_input(day: i32) -> Result<String, Box<dyn Error>> {
-    let cache_dir = Path::new("./puzzle_inputs");
-    let cache_path = cache_dir.join(format!("day{}.txt", day));
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Show every cached input with its metadata, flagging unusable ones
+    List,
+    /// Download the inputs for the given days again
+    Refresh {
+        #[arg(required = true)]
+        days: Vec<Day>,
+    },
+    /// Remove the inputs for the given days
+    Delete {
+        #[arg(required = true)]
+        days: Vec<Day>,
+    },
+}
 
-    if cache_path.exists() {
-        let s = std::fs::read_to_string(&cache_path)?;
+impl Commands {
+    /// Every day named on the command line.
+    fn days(&self) -> Vec<Day> {
+        match self {
+            Commands::Day { day, .. }
+            | Commands::Submit { day, .. }
+            | Commands::Watch { day }
+            | Commands::New { day, .. }
+            | Commands::Examples { day, .. } => vec![*day],
+            Commands::Run { days, .. }
+            | Commands::Verify { days, .. }
+            | Commands::Bench { days, .. } => days.clone(),
+            Commands::Cache {
+                action: CacheAction::Refresh { days } | CacheAction::Delete { days },
+            } => days.clone(),
+            Commands::Whoami | Commands::Cache { .. } => vec![],
+        }
+    }
+
+    fn all_profiles(&self) -> bool {
+        match self {
+            Commands::Day { all_profiles, .. } | Commands::Run { all_profiles, .. } => {
+                *all_profiles
+            }
+            _ => false,
+        }
+    }
+}
+
+fn input_cache(profile: &Profile, year: i32) -> InputCache {
+    InputCache::new(profile.year_dir(year))
+}
+
+fn cached_input(profile: &Profile, year: i32, day: i32) -> Result<Option<String>, Box<dyn Error>> {
+    input_cache(profile, year).read(day)
+}
+
+fn read_input_file(path: &Path) -> Result<String, Box<dyn Error>> {
+    if path == Path::new("-") {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s)?;
         return Ok(s);
     }
 
-    let url = format!("https://adventofcode.com/2025/day/{}/input", day);
-    let cookies = fs::read_to_string(".cookie")?.trim().to_string();
+    fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e).into())
+}
+
+/// An HTTP client that sends the profile's session cookie with every request.
+fn client(profile: &Profile) -> Result<Client, Box<dyn Error>> {
+    let session = Session::for_profile(profile)?;
 
     let mut headers = HeaderMap::new();
-    headers.insert(reqwest::header::COOKIE, HeaderValue::from_str(&cookies)?);
+    headers.insert(
+        reqwest::header::COOKIE,
+        HeaderValue::from_str(&session.cookie())?,
+    );
+
+    Ok(Client::builder().default_headers(headers).build()?)
+}
 
-    let client = Client::builder().default_headers(headers).build()?;
-    let resp = client.get(&url).send().await?;
-    let status = resp.status();
+/// Sends `request` and returns the response status and body, or why it
+/// failed.
+async fn fetch_with_status(request: RequestBuilder) -> Result<(u16, String), Box<dyn Error>> {
+    let resp = request.send().await?;
+    let status = resp.status().as_u16();
     let body = resp.text().await?;
 
-    if status != 200 {
-        return Err(format!("unexpected status {}: {}", status, body).into());
+    match FetchError::classify(status, &body) {
+        Some(err) => Err(err.into()),
+        None => Ok((status, body)),
     }
+}
 
-    fs::create_dir_all(&cache_dir)?;
-    fs::write(&cache_path, &body)?;
+/// Sends `request` and returns the response body, or why it failed.
+async fn fetch(request: RequestBuilder) -> Result<String, Box<dyn Error>> {
+    Ok(fetch_with_status(request).await?.1)
+}
+
+/// Refuses to ask the site about a puzzle that has not unlocked yet, or with
+/// `wait`, counts down until it does.
+async fn wait_for_unlock(config: &Config, day: i32, wait: bool) -> Result<(), Box<dyn Error>> {
+    let Some(left) = unlock::time_until_unlock(config.year, day, SystemTime::now()) else {
+        return Ok(());
+    };
+    if !wait {
+        return Err(format!(
+            "puzzle not unlocked yet: day {} of {} unlocks in {} (use --wait to wait for it)",
+            day,
+            config.year,
+            unlock::format_countdown(left)
+        )
+        .into());
+    }
+
+    while let Some(left) = unlock::time_until_unlock(config.year, day, SystemTime::now()) {
+        eprint!(
+            "\rDay {} unlocks in {} ",
+            day,
+            unlock::format_countdown(left)
+        );
+        let to_next_second = Duration::from_nanos(left.subsec_nanos() as u64);
+        tokio::time::sleep(if to_next_second.is_zero() {
+            Duration::from_secs(1)
+        } else {
+            to_next_second
+        })
+        .await;
+    }
+    eprintln!("\rDay {} is unlocked          ", day);
+
+    Ok(())
+}
+
+async fn get_input(
+    config: &Config,
+    profile: &Profile,
+    day: i32,
+    wait: bool,
+) -> Result<String, Box<dyn Error>> {
+    if let Some(s) = cached_input(profile, config.year, day)? {
+        return Ok(s);
+    }
+    download_input(config, profile, day, wait).await
+}
+
+/// Downloads the profile's input for `day` and stores it in its cache,
+/// replacing whatever was there.
+async fn download_input(
+    config: &Config,
+    profile: &Profile,
+    day: i32,
+    wait: bool,
+) -> Result<String, Box<dyn Error>> {
+    wait_for_unlock(config, day, wait).await?;
+
+    let (status, body) = fetch_with_status(client(profile)?.get(config.input_url(day))).await?;
+    let fetched_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    input_cache(profile, config.year).write(
+        day,
+        &body,
+        &Metadata::new(&body, status, fetched_at),
+    )?;
 
     Ok(body)
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let cli = Cli::parse();
+/// The puzzle description page, from `html` if given, otherwise from the site.
+async fn puzzle_description(
+    config: &Config,
+    profile: &Profile,
+    day: i32,
+    html: Option<&Path>,
+) -> Result<String, Box<dyn Error>> {
+    if let Some(path) = html {
+        return read_input_file(path);
+    }
+    wait_for_unlock(config, day, false).await?;
 
-    match &cli.command {
-        Commands::Day { day } => {
-            let data = get_input(day.clone()).await?;
+    fetch(client(profile)?.get(config.puzzle_url(day))).await
+}
 
-            match day {
-                1 => {
-                    let puzzle = day1::Day1;
-                    puzzle.solve(&data);
-                }
-                2 => {
-                    let puzzle = day2::Day2;
-                    puzzle.solve(&data);
+async fn post_answer(
+    config: &Config,
+    profile: &Profile,
+    day: i32,
+    part: u8,
+    answer: &str,
+) -> Result<(Verdict, String), Box<dyn Error>> {
+    let level = part.to_string();
+    let body = fetch(
+        client(profile)?
+            .post(config.answer_url(day))
+            .form(&[("level", level.as_str()), ("answer", answer)]),
+    )
+    .await?;
+
+    Ok((
+        Verdict::from_response(&body),
+        submit::response_message(&body),
+    ))
+}
+
+/// How `day` solves an input and reports the answers.
+struct SolveOptions {
+    parts: Vec<u8>,
+    repeat: u32,
+    /// Print each answer as it comes in.
+    text: bool,
+    timed: bool,
+}
+
+impl SolveOptions {
+    fn new(part: Option<u8>, repeat: u32, time: bool, format: Format) -> SolveOptions {
+        SolveOptions {
+            parts: match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            },
+            repeat,
+            text: matches!(format, Format::Text),
+            timed: time || repeat > 1,
+        }
+    }
+}
+
+/// Solves one input. `note` may add a remark to a part's answer line.
+fn solve_input(
+    puzzle: &dyn puzzles::Solver,
+    day: i32,
+    data: &str,
+    options: &SolveOptions,
+    note: impl Fn(u8, &str) -> Option<String>,
+) -> Result<Vec<Record>, PuzzleError> {
+    let repeat = options.repeat as usize;
+    let (parsed, parse_timing) = runner::run_parse(puzzle, day, data, repeat)?;
+    let mut records = vec![Record::parsed(day, parse_timing)];
+    if options.text && options.timed {
+        println!("Parsed Day {} input  ({})", day, parse_timing);
+    }
+
+    for &part in &options.parts {
+        let run = runner::run_part(parsed.as_ref(), day, part, repeat)?;
+        records.push(Record::solved(day, &run));
+        if !options.text {
+            continue;
+        }
+
+        let mut line = format!("Answer of Day {} Part {}:  {}", day, run.part, run.answer);
+        if options.timed {
+            line += &format!("  ({})", run.timing);
+        }
+        if let Some(note) = note(part, &run.answer.to_string()) {
+            line += &format!("  {}", note);
+        }
+        println!("{}", line);
+    }
+
+    Ok(records)
+}
+
+/// Solves the selected days on the profile's cached inputs, recording
+/// failures instead of stopping at them.
+fn run_days(
+    profile: &Profile,
+    year: i32,
+    selected: &[(i32, Option<&dyn puzzles::Solver>)],
+    repeat: usize,
+) -> Vec<Record> {
+    let mut records = vec![];
+    for &(day, puzzle) in selected {
+        let Some(puzzle) = puzzle else {
+            records.push(Record::unsolved(day, Status::NotFound));
+            continue;
+        };
+        let data = match cached_input(profile, year, day) {
+            Ok(Some(data)) => data,
+            Ok(None) => {
+                records.push(Record::unsolved(day, Status::Skipped));
+                continue;
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                records.push(Record::unsolved(day, Status::Skipped));
+                continue;
+            }
+        };
+
+        let (parsed, parse_timing) = match runner::run_parse(puzzle, day, &data, repeat) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                records.push(Record::failed(day, None, &err));
+                continue;
+            }
+        };
+        records.push(Record::parsed(day, parse_timing));
+
+        for part in [1, 2] {
+            match runner::run_part(parsed.as_ref(), day, part, repeat) {
+                Ok(run) => records.push(Record::solved(day, &run)),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    records.push(Record::failed(day, Some(part), &err));
                 }
-                3 => {
-                    let puzzle = day3::Day3;
-                    puzzle.solve(&data);
+            }
+        }
+    }
+
+    records
+}
+
+fn cargo() -> process::Command {
+    process::Command::new(std::env::var("CARGO").unwrap_or("cargo".into()))
+}
+
+/// `cargo run` of this binary with the same global options.
+fn cargo_run(cli: &Cli, args: &[&str]) -> process::Command {
+    let mut command = cargo();
+    command.args(["run", "--quiet", "--"]);
+    if let Some(base_url) = &cli.base_url {
+        command.args(["--base-url", base_url]);
+    }
+    if let Some(year) = cli.year {
+        command.args(["--year", &year.to_string()]);
+    }
+    if let Some(profile) = &cli.profile {
+        command.args(["--profile", profile]);
+    }
+    command.args(args);
+    command
+}
+
+/// Rebuilds and reruns `day` on its examples and input, returning the
+/// answers on the input, or `None` if the build or the run failed.
+fn rerun_day(cli: &Cli, day: i32) -> Result<Option<BTreeMap<u8, String>>, Box<dyn Error>> {
+    let day = day.to_string();
+
+    if !cargo().args(["build", "--quiet"]).status()?.success() {
+        return Ok(None);
+    }
+    // Mismatching examples are reported by the run itself.
+    cargo_run(cli, &["day", &day, "--example"]).status()?;
+
+    let output = cargo_run(cli, &["day", &day, "--format", "json"])
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+
+    // Anything else the run prints makes the report unreadable; that is a
+    // failed run too, not a reason to stop watching.
+    match watch::answers_from_json(&String::from_utf8_lossy(&output.stdout)) {
+        Ok(answers) => Ok(Some(answers)),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            Ok(None)
+        }
+    }
+}
+
+/// Times each stage of the selected days on the profile's cached inputs.
+/// Days without an input, or that fail, are reported and left out.
+fn bench_days(
+    profile: &Profile,
+    year: i32,
+    days: &[i32],
+    repeat: usize,
+) -> Result<Vec<Measurement>, Box<dyn Error>> {
+    let mut measurements = vec![];
+    for &day in days {
+        let puzzle = puzzles::find(day).ok_or(format!("Puzzle of day {} not found", day))?;
+        let Some(data) = cached_input(profile, year, day)? else {
+            eprintln!("Day {}: skipped (no cached input)", day);
+            continue;
+        };
+
+        let (parsed, timing) = match runner::run_parse(puzzle, day, &data, repeat) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                continue;
+            }
+        };
+        measurements.push(Measurement {
+            day,
+            stage: Stage::Parse,
+            timing,
+        });
+
+        for (part, stage) in [(1, Stage::Part1), (2, Stage::Part2)] {
+            match runner::run_part(parsed.as_ref(), day, part, repeat) {
+                Ok(run) => measurements.push(Measurement {
+                    day,
+                    stage,
+                    timing: run.timing,
+                }),
+                Err(err) => eprintln!("error: {}", err),
+            }
+        }
+    }
+
+    Ok(measurements)
+}
+
+fn print_cache(cache: &InputCache) -> Result<(), Box<dyn Error>> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+    println!(
+        "{:>3}  {:>8}  {:>6}  {:<16}  {:<16}  State",
+        "Day", "Bytes", "Status", "Fetched", "SHA-256"
+    );
+    for entry in cache.list()? {
+        let (status, fetched, hash) = match &entry.metadata {
+            Some(metadata) => (
+                metadata.status.to_string(),
+                format!(
+                    "{} ago",
+                    unlock::format_countdown(Duration::from_secs(
+                        now.saturating_sub(metadata.fetched_at)
+                    ))
+                ),
+                metadata.sha256[..16].to_string(),
+            ),
+            None => ("-".to_string(), "-".to_string(), "-".to_string()),
+        };
+        let state = match (&entry.problem, &entry.metadata) {
+            (Some(problem), _) => format!("unusable: {}", problem),
+            (None, Some(_)) => "ok".to_string(),
+            (None, None) => "ok (no metadata)".to_string(),
+        };
+
+        println!(
+            "{:>3}  {:>8}  {:>6}  {:<16}  {:<16}  {}",
+            entry.day, entry.bytes, status, fetched, hash, state
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints `records` in a machine-readable `format`; text output is printed
+/// as the records are made.
+fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", report::to_json(records)),
+        Format::Tsv => println!("{}", report::to_tsv(records)),
+    }
+}
+
+fn print_table(records: &[Record], with_spread: bool) {
+    if with_spread {
+        println!(
+            "{:>3}  {:>5}  {:<20}  {:>10}  {:>10}  {:>10}",
+            "Day", "Part", "Answer", "Min", "Median", "Max"
+        );
+    } else {
+        println!(
+            "{:>3}  {:>5}  {:<20}  {:>10}",
+            "Day", "Part", "Answer", "Time"
+        );
+    }
+
+    for record in records {
+        let (part, answer) = match (record.status, record.part, &record.answer) {
+            (Status::Parsed, _, _) => ("parse".to_string(), ""),
+            (Status::Solved, Some(part), Some(answer)) => (part.to_string(), answer.as_str()),
+            (status, _, _) => {
+                let reason = match status {
+                    Status::NotFound => "not found",
+                    Status::Failed => "failed (see error above)",
+                    Status::Mismatch => "example answer mismatch",
+                    _ => "skipped (no usable cached input)",
+                };
+                println!("{:>3}  {:>5}  {}", record.day, "-", reason);
+                continue;
+            }
+        };
+        let Some(timing) = record.timing else {
+            continue;
+        };
+
+        if with_spread {
+            println!(
+                "{:>3}  {:>5}  {:<20}  {:>10}  {:>10}  {:>10}",
+                record.day,
+                part,
+                answer,
+                format!("{:.2?}", timing.min),
+                format!("{:.2?}", timing.median),
+                format!("{:.2?}", timing.max)
+            );
+        } else {
+            println!(
+                "{:>3}  {:>5}  {:<20}  {:>10}",
+                record.day,
+                part,
+                answer,
+                format!("{:.2?}", timing.min)
+            );
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    if let Err(err) = run(Cli::parse()).await {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let mut config = Config::load()?;
+    if let Some(base_url) = &cli.base_url {
+        config = config.with_base_url(base_url);
+    }
+    if let Some(year) = cli.year {
+        config = config.with_year(year)?;
+    }
+    for day in cli.command.days() {
+        if let Err(e) = day.check_year(config.year) {
+            Cli::command().error(ErrorKind::ValueValidation, e).exit();
+        }
+    }
+    // `--profile` is global, so clap cannot tell whether it came before
+    // the subcommand; check the combination here instead.
+    if cli.profile.is_some() && cli.command.all_profiles() {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "the argument '--all-profiles' cannot be used with '--profile <NAME>'",
+            )
+            .exit();
+    }
+    let profile = config.profile(cli.profile.as_deref())?;
+
+    match &cli.command {
+        Commands::Day {
+            day,
+            part,
+            input,
+            example,
+            wait,
+            time,
+            repeat,
+            format,
+            all_profiles,
+        } => match puzzles::find(day.get()) {
+            Some(puzzle) if *example => {
+                let text = matches!(format, Format::Text);
+                if puzzle.examples().is_empty() {
+                    if text {
+                        println!("Day {} has no registered examples", day);
+                    } else {
+                        eprintln!("Day {} has no registered examples", day);
+                    }
                 }
-                4 => {
-                    let puzzle = day4::Day4;
-                    puzzle.solve(&data);
+
+                let solver = puzzle.example_solver();
+                let mut records = vec![];
+                for (idx, example) in puzzle.examples().iter().enumerate() {
+                    for (part_no, expected) in [(1, example.part1), (2, example.part2)] {
+                        let Some(expected) = expected else {
+                            continue;
+                        };
+                        if part.is_some_and(|part| part != part_no) {
+                            continue;
+                        }
+
+                        let got = match part_no {
+                            1 => solver.part1(example.input),
+                            _ => solver.part2(example.input),
+                        }
+                        .map_err(|e| e.with_day(day.get()))?;
+                        let record = Record::example(day.get(), idx + 1, part_no, &got, expected);
+                        if text {
+                            println!(
+                                "Example {} of Day {} Part {}:  got {}, expected {}  {}",
+                                idx + 1,
+                                day,
+                                part_no,
+                                got,
+                                expected,
+                                if record.status == Status::Mismatch {
+                                    "MISMATCH"
+                                } else {
+                                    "ok"
+                                }
+                            );
+                        }
+                        records.push(record);
+                    }
                 }
-                5 => {
-                    let puzzle = day5::Day5;
-                    puzzle.solve(&data);
+
+                print_records(*format, &records);
+                let mismatches = records
+                    .iter()
+                    .filter(|record| record.status == Status::Mismatch)
+                    .count();
+                if mismatches > 0 {
+                    return Err(format!("{} example answer(s) did not match", mismatches).into());
                 }
-                6 => {
-                    let puzzle = day6::Day6;
-                    puzzle.solve(&data);
+            }
+            Some(puzzle) if *all_profiles => {
+                let options = SolveOptions::new(*part, *repeat, *time, *format);
+                let mut records = vec![];
+                let mut problems = 0;
+                for profile in config.all_profiles() {
+                    if options.text {
+                        println!("Profile {}:", profile.label());
+                    }
+                    let answers = Answers::load(&profile.answers_path(config.year))?;
+                    let note = |part: u8, answer: &str| match answers.check(day.get(), part, answer)
+                    {
+                        Outcome::Matching => Some("matches the stored answer".to_string()),
+                        Outcome::Changed { expected } => {
+                            Some(format!("CHANGED, expected {}", expected))
+                        }
+                        Outcome::Unknown => None,
+                    };
+                    let solved = match get_input(&config, &profile, day.get(), *wait).await {
+                        Ok(data) => {
+                            solve_input(puzzle, day.get(), &data, &options, note).map_err(Box::from)
+                        }
+                        Err(err) => Err(err),
+                    };
+
+                    match solved {
+                        Ok(solved) => {
+                            for record in solved {
+                                if let (Some(part), Some(answer)) = (record.part, &record.answer) {
+                                    if let Outcome::Changed { .. } =
+                                        answers.check(day.get(), part, answer)
+                                    {
+                                        problems += 1;
+                                    }
+                                }
+                                records.push(record.with_profile(profile.label()));
+                            }
+                        }
+                        Err(err) => {
+                            eprintln!("error: profile {}: {}", profile.label(), err);
+                            problems += 1;
+                        }
+                    }
                 }
-                7 => {
-                    let puzzle = day7::Day7;
-                    puzzle.solve(&data);
+
+                print_records(*format, &records);
+                if problems > 0 {
+                    return Err(
+                        format!("{} failed profile(s) or changed answer(s)", problems).into(),
+                    );
                 }
-                8 => {
-                    let puzzle = day8::Day8;
-                    puzzle.solve(&data);
+            }
+            Some(puzzle) => {
+                let data = match input {
+                    Some(path) => read_input_file(path)?,
+                    None => get_input(&config, &profile, day.get(), *wait).await?,
+                };
+                let options = SolveOptions::new(*part, *repeat, *time, *format);
+                let records = solve_input(puzzle, day.get(), &data, &options, |_, _| None)?;
+
+                print_records(*format, &records);
+            }
+            None => return Err(format!("Puzzle of day {} not found", day).into()),
+        },
+        Commands::Run {
+            all,
+            days,
+            repeat,
+            format,
+            all_profiles,
+        } => {
+            let selected: Vec<(i32, Option<&dyn puzzles::Solver>)> = if *all {
+                puzzles::PUZZLES
+                    .iter()
+                    .map(|(day, puzzle)| (*day, Some(*puzzle)))
+                    .collect()
+            } else {
+                days.iter()
+                    .map(|day| (day.get(), puzzles::find(day.get())))
+                    .collect()
+            };
+
+            let profiles = if *all_profiles {
+                config.all_profiles()
+            } else {
+                vec![profile.clone()]
+            };
+            let mut records = vec![];
+            for profile in &profiles {
+                let solved = run_days(profile, config.year, &selected, *repeat as usize);
+                if matches!(format, Format::Text) {
+                    if *all_profiles {
+                        println!("Profile {}:", profile.label());
+                    }
+                    print_table(&solved, *repeat > 1);
                 }
-                9 => {
-                    let puzzle = day9::Day9;
-                    puzzle.solve(&data);
+                if *all_profiles {
+                    records.extend(
+                        solved
+                            .into_iter()
+                            .map(|record| record.with_profile(profile.label())),
+                    );
+                } else {
+                    records.extend(solved);
                 }
-                10 => {
-                    let puzzle = day10::Day10;
-                    puzzle.solve(&data);
+            }
+
+            print_records(*format, &records);
+        }
+        Commands::Submit { day, part, force } => {
+            let puzzle =
+                puzzles::find(day.get()).ok_or(format!("Puzzle of day {} not found", day))?;
+            let data = get_input(&config, &profile, day.get(), false).await?;
+            let (parsed, _) = runner::run_parse(puzzle, day.get(), &data, 1)?;
+            let answer = runner::run_part(parsed.as_ref(), day.get(), *part, 1)?
+                .answer
+                .to_string();
+
+            let store_path = profile.submissions_path();
+            let mut store = Submissions::load(&store_path)?;
+            match store.check(config.year, day.get(), *part, &answer) {
+                Check::New => {}
+                Check::AlreadyCorrect => {
+                    println!("Day {} Part {} is already solved", day, part);
+                    return Ok(());
                 }
-                11 => {
-                    let puzzle = day11::Day11;
-                    puzzle.solve(&data);
+                Check::KnownWrong(_) => {
+                    return Err(format!("{} was already submitted and was wrong", answer).into());
                 }
-                _ => {
-                    println!("Puzzle of day {:#?} not found!", day);
+                Check::OutOfBounds(bound) => {
+                    let side = match bound.verdict {
+                        Verdict::TooHigh => "too high",
+                        _ => "too low",
+                    };
+                    eprintln!(
+                        "warning: {} was {}, so {} is wrong too",
+                        bound.answer, side, answer
+                    );
+                    if !*force {
+                        return Err("not submitting (use --force to submit anyway)".into());
+                    }
                 }
             }
-        }
-        Commands::New { day } => {
-            let solution_path = Path::new("./src/puzzles").join(format!("day{}.rs", day));
-            if solution_path.exists() {
-                panic!("Solution file {:?} exists", solution_path);
+
+            println!("Submitting {} for Day {} Part {}", answer, day, part);
+            let (verdict, message) =
+                post_answer(&config, &profile, day.get(), *part, &answer).await?;
+            println!("{}", message);
+
+            if verdict == Verdict::Correct {
+                let answers_path = profile.answers_path(config.year);
+                let mut answers = Answers::load(&answers_path)?;
+                answers.insert(day.get(), *part, answer.clone());
+                answers.save(&answers_path)?;
             }
-            println!("Path: {:?}", solution_path);
-            let mut solution_file: fs::File = fs::File::create(solution_path)?;
-            writeln!(solution_file, "use super::Puzzle;\n")?;
-            writeln!(solution_file, "pub struct Day{};\n", day)?;
-            writeln!(solution_file, "type Input = ();\n")?;
-            writeln!(
-                solution_file,
-                "mod parser {{\n    use nom::IResult;\n\n    use super::Input;\n"
-            )?;
-            writeln!(
-                solution_file,
-                "    pub fn parse(input: &str) -> IResult<&str, Input> {{"
-            )?;
-            writeln!(solution_file, "        Ok((\"\", ()))\n    }}\n}}\n")?;
-            writeln!(solution_file, "impl Puzzle for Day{} {{", day)?;
-            writeln!(solution_file, "    type Output = i64;\n")?;
-            writeln!(
-                solution_file,
-                "    fn part1(&self, input: &str) -> Self::Output {{\n        0\n    }}\n"
-            )?;
-            writeln!(
-                solution_file,
-                "    fn part2(&self, input: &str) -> Self::Output {{\n        0\n    }}\n"
-            )?;
-            writeln!(solution_file, "    fn solve(&self, input: &str) {{")?;
-            writeln!(solution_file, "            let ans1 = self.part1(&input);")?;
-            writeln!(
-                solution_file,
-                "            println!(\"Answer of Day {} Part 1:  {{:#?}}\", ans1);",
-                day
-            )?;
-            writeln!(solution_file, "            let ans2 = self.part2(&input);")?;
-            writeln!(
-                solution_file,
-                "            println!(\"Answer of Day {} Part 2:  {{:#?}}\", ans2);",
-                day
-            )?;
-            writeln!(solution_file, "        }}")?;
-            writeln!(solution_file, "}}\n")?;
-            writeln!(solution_file, "#[cfg(test)]")?;
-            writeln!(solution_file, "mod tests {{")?;
-            writeln!(solution_file, "    use super::*;\n")?;
-            writeln!(solution_file, "    const TESTCASE: &'static str = r\"\";\n")?;
-            writeln!(
-                solution_file,
-                "    #[test]\n    fn test_puzzle_day{}_parse() {{\n\n    }}\n",
-                day
-            )?;
-            writeln!(
-                solution_file,
-                "    #[test]\n    fn test_puzzle_day{}_part1() {{",
-                day
-            )?;
-            writeln!(solution_file, "        let puzzle = Day{};\n", day)?;
-            writeln!(
-                solution_file,
-                "        assert_eq!(puzzle.part1(TESTCASE), 0);"
-            )?;
-            writeln!(solution_file, "    }}\n")?;
-            writeln!(
-                solution_file,
-                "    #[test]\n    fn test_puzzle_day{}_part2() {{",
-                day
-            )?;
-            writeln!(solution_file, "        let puzzle = Day{};\n", day)?;
-            writeln!(
-                solution_file,
-                "        assert_eq!(puzzle.part2(TESTCASE), 0);"
-            )?;
-            writeln!(solution_file, "    }}\n")?;
-            writeln!(solution_file, "}}")?;
 
-            let all_mod_line = fs::read_to_string(Path::new("./src/puzzles").join("mod.rs"))?;
-            let mut all_mod_lines: Vec<String> = all_mod_line
-                .trim()
-                .split("\n")
-                .map(|line| line.to_string())
-                .collect::<Vec<String>>();
+            store.record(Submission {
+                year: config.year,
+                day: day.get(),
+                part: *part,
+                answer,
+                verdict,
+                submitted_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            });
+            store.save(&store_path)?;
+        }
+        Commands::Verify { days, record } => {
+            let answers_path = profile.answers_path(config.year);
+            let mut answers = Answers::load(&answers_path)?;
+            let selected: Vec<i32> = if days.is_empty() {
+                puzzles::PUZZLES.iter().map(|(day, _)| *day).collect()
+            } else {
+                days.iter().map(|day| day.get()).collect()
+            };
+
+            println!(
+                "{:>3}  {:>4}  {:<9}  {:<20}  Expected",
+                "Day", "Part", "Status", "Answer"
+            );
+            let mut regressions = 0;
+            let mut recorded = vec![];
+            for day in selected {
+                let puzzle =
+                    puzzles::find(day).ok_or(format!("Puzzle of day {} not found", day))?;
+                let data = match cached_input(&profile, config.year, day) {
+                    Ok(Some(data)) => data,
+                    Ok(None) => {
+                        println!("{:>3}  {:>4}  skipped (no cached input)", day, "-");
+                        continue;
+                    }
+                    Err(err) => {
+                        eprintln!("error: {}", err);
+                        println!("{:>3}  {:>4}  failed (unusable cached input)", day, "-");
+                        regressions += 1;
+                        continue;
+                    }
+                };
+
+                let results: Vec<_> = match runner::run_parse(puzzle, day, &data, 1) {
+                    Ok((parsed, _)) => [1, 2]
+                        .into_iter()
+                        .map(|part| (part, runner::run_part(parsed.as_ref(), day, part, 1)))
+                        .collect(),
+                    Err(err) => vec![(1, Err(err.clone())), (2, Err(err))],
+                };
+
+                for (part, result) in results {
+                    let expected = answers.get(day, part).unwrap_or("");
+                    let run = match result {
+                        Ok(run) => run,
+                        Err(err) => {
+                            eprintln!("error: {}", err);
+                            println!(
+                                "{:>3}  {:>4}  {:<9}  {:<20}  {}",
+                                day, part, "failed", "-", expected
+                            );
+                            if !expected.is_empty() {
+                                regressions += 1;
+                            }
+                            continue;
+                        }
+                    };
+
+                    let answer = run.answer.to_string();
+                    let status = match answers.check(day, part, &answer) {
+                        Outcome::Matching => "matching",
+                        Outcome::Changed { .. } => {
+                            regressions += 1;
+                            "CHANGED"
+                        }
+                        Outcome::Unknown => {
+                            recorded.push((day, part, answer.clone()));
+                            "unknown"
+                        }
+                    };
+                    println!(
+                        "{:>3}  {:>4}  {:<9}  {:<20}  {}",
+                        day, part, status, answer, expected
+                    );
+                }
+            }
 
-            let insert_idx = all_mod_lines
-                .iter()
-                .filter(|&line| line.starts_with("pub mod day"))
-                .count();
+            if *record && !recorded.is_empty() {
+                for (day, part, answer) in recorded {
+                    answers.insert(day, part, answer);
+                }
+                answers.save(&answers_path)?;
+                println!("Recorded new answers in {}", answers_path.display());
+            }
 
-            let new_mod_line = format!("pub mod day{};", day);
-            if !all_mod_lines.contains(&new_mod_line) {
-                all_mod_lines.insert(insert_idx, new_mod_line);
+            if regressions > 0 {
+                return Err(format!("{} answer(s) changed or failed", regressions).into());
             }
+        }
+        Commands::Bench {
+            days,
+            repeat,
+            threshold,
+            output,
+            baseline,
+            accept,
+        } => {
+            let saved = bench::load(output)?;
+            let baseline = match baseline {
+                Some(path) => bench::load(path)?,
+                None => saved.clone(),
+            };
+            let selected: Vec<i32> = if days.is_empty() {
+                puzzles::PUZZLES.iter().map(|(day, _)| *day).collect()
+            } else {
+                days.iter().map(|day| day.get()).collect()
+            };
 
-            let mut solution_mod_file = fs::OpenOptions::new()
-                .write(true)
-                .truncate(true)
-                .open(Path::new("./src/puzzles").join("mod.rs"))?;
+            let measurements = bench_days(&profile, config.year, &selected, *repeat as usize)?;
+            let comparisons = bench::compare(&baseline, &measurements, *threshold);
 
-            writeln!(solution_mod_file, "{}", all_mod_lines.join("\n"))?;
+            println!(
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>8}",
+                "Day", "Stage", "Median", "Baseline", "Change"
+            );
+            for comparison in &comparisons {
+                let Measurement { day, stage, timing } = comparison.measurement;
+                println!(
+                    "{:>3}  {:<5}  {:>10}  {:>10}  {:>8}{}",
+                    day,
+                    stage.as_str(),
+                    format!("{:.2?}", timing.median),
+                    comparison
+                        .baseline
+                        .map_or("-".to_string(), |baseline| format!("{:.2?}", baseline)),
+                    comparison
+                        .change
+                        .map_or("-".to_string(), |change| format!("{:+.1}%", change)),
+                    if comparison.regressed {
+                        "  REGRESSION"
+                    } else {
+                        ""
+                    }
+                );
+            }
 
-            let main_path = Path::new("./src/main.rs");
-            let main_text = fs::read_to_string(main_path)?;
+            // Writing slow results would make them the baseline of the
+            // next run, which would then find nothing wrong.
+            let regressions = comparisons.iter().filter(|c| c.regressed).count();
+            if regressions == 0 || *accept {
+                bench::save(output, &bench::merge(&saved, &measurements))?;
+                println!("Wrote {}", output.display());
+            } else {
+                println!(
+                    "Left {} unchanged (use --accept to make these results the baseline)",
+                    output.display()
+                );
+            }
 
-            if !main_text.contains(&format!("day{}::Day{}", day, day)) {
-                let mut main_lines: Vec<String> =
-                    main_text.lines().map(|l| l.to_string()).collect();
+            if regressions > 0 {
+                return Err(format!(
+                    "{} stage(s) slower than the baseline by more than {}%",
+                    regressions, threshold
+                )
+                .into());
+            }
+        }
+        Commands::Watch { day } => {
+            let mut watcher = Watcher::new(vec![
+                Path::new("./src/puzzles").join(format!("day{}.rs", day)),
+                input_cache(&profile, config.year).input_path(day.get()),
+            ]);
+            let mut previous = None;
+            loop {
+                match rerun_day(&cli, day.get())? {
+                    Some(answers) => {
+                        for line in watch::diff_answers(day.get(), previous.as_ref(), &answers) {
+                            println!("{}", line);
+                        }
+                        previous = Some(answers);
+                    }
+                    None => println!("Day {} failed to build or run", day),
+                }
 
-                let insert_idx = main_lines
+                let watched: Vec<_> = watcher
+                    .paths()
                     .iter()
-                    .position(|l| l.contains("_ => {"))
-                    .unwrap_or(main_lines.len());
+                    .map(|path| path.display().to_string())
+                    .collect();
+                println!("Watching {} (Ctrl-C to stop)", watched.join(" and "));
+                while !watcher.changed() {
+                    tokio::time::sleep(watch::POLL_INTERVAL).await;
+                }
+                // Let editors that save in several writes finish.
+                tokio::time::sleep(watch::POLL_INTERVAL).await;
+                watcher.changed();
+                println!();
+            }
+        }
+        Commands::Whoami => {
+            let session = Session::for_profile(&profile)?;
+            let page = fetch(client(&profile)?.get(config.event_url())).await?;
+            let user = session::logged_in_user(&page).ok_or(FetchError::SessionExpired)?;
+            println!("Logged in as {} (session from {})", user, session.source);
+        }
+        Commands::Cache { action } => match action {
+            CacheAction::List => print_cache(&input_cache(&profile, config.year))?,
+            CacheAction::Refresh { days } => {
+                for day in days {
+                    let input = download_input(&config, &profile, day.get(), false).await?;
+                    println!("Day {}: downloaded {} bytes", day, input.len());
+                }
+            }
+            CacheAction::Delete { days } => {
+                let cache = input_cache(&profile, config.year);
+                for day in days {
+                    if cache.delete(day.get())? {
+                        println!(
+                            "Day {}: deleted {}",
+                            day,
+                            cache.input_path(day.get()).display()
+                        );
+                    } else {
+                        println!("Day {}: not cached", day);
+                    }
+                }
+            }
+        },
+        Commands::Examples { day, html } => {
+            let solution_path = Path::new("./src/puzzles").join(format!("day{}.rs", day));
+            let source = fs::read_to_string(&solution_path)
+                .map_err(|e| format!("cannot read {}: {}", solution_path.display(), e))?;
+            let html = puzzle_description(&config, &profile, day.get(), html.as_deref()).await?;
+            let part2 = PuzzleExamples::from_html(&html)
+                .part2
+                .ok_or(format!("part 2 of day {} is not unlocked yet", day))?;
 
-                let new_arm = format!(
-                    "                {d} => {{\n                    let puzzle = day{d}::Day{d};\n                    puzzle.solve(&data);\n                }}",
-                    d = day
-                );
+            let source = scaffold::add_part2_example(&source, &part2).map_err(|e| {
+                format!(
+                    "cannot add the part 2 example to {}: {}",
+                    solution_path.display(),
+                    e
+                )
+            })?;
+            fs::write(&solution_path, source)?;
+            println!("Added the part 2 example to {}", solution_path.display());
+        }
+        Commands::New {
+            day,
+            html,
+            template,
+            output,
+            dry_run,
+            force,
+        } => {
+            let solution_path = Path::new("./src/puzzles").join(format!("day{}.rs", day));
+            let mod_path = Path::new("./src/puzzles").join("mod.rs");
+            let mod_text = fs::read_to_string(&mod_path)
+                .map_err(|e| format!("cannot read {}: {}", mod_path.display(), e))?;
+            let plan = NewDay::plan(
+                day.get(),
+                &solution_path,
+                solution_path.exists(),
+                *force,
+                &mod_path,
+                &mod_text,
+            )?;
+            let template = scaffold::load_template(template)?;
 
-                main_lines.insert(insert_idx, new_arm);
-                fs::write(main_path, main_lines.join("\n"))?;
+            // A dry run does not ask the site for the description, but still
+            // checks that the template renders.
+            if *dry_run {
+                scaffold::solution_module(&template, day.get(), output, &PuzzleExamples::default())
+                    .map_err(|e| format!("cannot render the template: {}", e))?;
+                for line in plan.dry_run()? {
+                    println!("{}", line);
+                }
+                return Ok(());
+            }
+
+            let examples =
+                match puzzle_description(&config, &profile, day.get(), html.as_deref()).await {
+                    Ok(html) => PuzzleExamples::from_html(&html),
+                    Err(e) => {
+                        eprintln!(
+                            "warning: no examples filled in, cannot get the puzzle description: {}",
+                            e
+                        );
+                        PuzzleExamples::default()
+                    }
+                };
+            let source = scaffold::solution_module(&template, day.get(), output, &examples)
+                .map_err(|e| format!("cannot render the template: {}", e))?;
+
+            println!("Path: {:?}", solution_path);
+            fs::write(&solution_path, source)?;
+            match plan.registered? {
+                Some(registered) => fs::write(&mod_path, registered)?,
+                None => println!(
+                    "Day {} is already registered in {}",
+                    day,
+                    mod_path.display()
+                ),
             }
         }
     };
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,77 +1,94 @@
-use super::Puzzle;
+use super::{Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day1;
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
-enum Rotation {
-    LEFT,
-    RIGHT,
+pub enum Rotation {
+    Left,
+    Right,
 }
 
-impl Day1 {
-    fn parse(&self, input: &str) -> Vec<(Rotation, i32)> {
+const TESTCASE: &str = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+impl Puzzle for Day1 {
+    type Parsed<'a> = Vec<(Rotation, i32)>;
+    type Output = i32;
+
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("3"),
+        part2: Some("6"),
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
         input
             .trim()
             .split("\n")
             .map(|instruction| {
-                let rotation = match &instruction[0..1] {
-                    "L" => Rotation::LEFT,
-                    "R" => Rotation::RIGHT,
-                    _ => unreachable!(),
+                let rotation = match instruction.chars().next() {
+                    Some('L') => Rotation::Left,
+                    Some('R') => Rotation::Right,
+                    _ => {
+                        return Err(PuzzleError::at(
+                            input,
+                            instruction,
+                            format!("unexpected rotation in {:?}", instruction),
+                        ))
+                    }
                 };
-                let distance = instruction[1..].parse().unwrap();
+                let distance = &instruction[1..];
+                let distance = distance.parse().map_err(|_| {
+                    PuzzleError::at(input, distance, format!("invalid distance {:?}", distance))
+                })?;
 
-                (rotation, distance)
+                Ok((rotation, distance))
             })
             .collect()
     }
-}
-
-impl Puzzle for Day1 {
-    type Output = i32;
-
-    fn part1(&self, input: &str) -> Self::Output {
-        self.parse(input)
+    fn part1(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(input
             .iter()
             .fold((50, 0), |acc, x| {
                 let next = match x.0 {
-                    Rotation::LEFT => (acc.0 - x.1) % 100,
-                    Rotation::RIGHT => (acc.0 + x.1) % 100,
+                    Rotation::Left => (acc.0 - x.1) % 100,
+                    Rotation::Right => (acc.0 + x.1) % 100,
                 };
 
                 (next, acc.1 + if next == 0 { 1 } else { 0 })
             })
-            .1
+            .1)
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        self.parse(input)
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(input
             .iter()
             .fold((50, 0), |acc, x| {
                 let difference = x.1 % 100;
                 let cycle = x.1 / 100;
 
                 let next = match x.0 {
-                    Rotation::LEFT => (
+                    Rotation::Left => (
                         (acc.0 - difference + 100) % 100,
                         (acc.0 <= difference && acc.0 > 0),
                     ),
-                    Rotation::RIGHT => ((acc.0 + difference) % 100, acc.0 + difference >= 100),
+                    Rotation::Right => ((acc.0 + difference) % 100, acc.0 + difference >= 100),
                 };
 
                 let zeros = cycle + if next.1 { 1 } else { 0 };
 
                 (next.0, acc.1 + zeros)
             })
-            .1
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 1 Part 1:  {:#?}", ans1);
-
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 1 Part 2:  {:#?}", ans2);
+            .1)
     }
 }
 
@@ -79,47 +96,49 @@ impl Puzzle for Day1 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82";
-
     #[test]
     fn test_puzzle_day1_parse() {
         let puzzle = Day1;
-        let instructions = puzzle.parse(TESTCASE);
+        let instructions = puzzle.parse(TESTCASE).unwrap();
         assert_eq!(
             instructions,
             vec![
-                (Rotation::LEFT, 68),
-                (Rotation::LEFT, 30),
-                (Rotation::RIGHT, 48),
-                (Rotation::LEFT, 5),
-                (Rotation::RIGHT, 60),
-                (Rotation::LEFT, 55),
-                (Rotation::LEFT, 1),
-                (Rotation::LEFT, 99),
-                (Rotation::RIGHT, 14),
-                (Rotation::LEFT, 82)
+                (Rotation::Left, 68),
+                (Rotation::Left, 30),
+                (Rotation::Right, 48),
+                (Rotation::Left, 5),
+                (Rotation::Right, 60),
+                (Rotation::Left, 55),
+                (Rotation::Left, 1),
+                (Rotation::Left, 99),
+                (Rotation::Right, 14),
+                (Rotation::Left, 82)
             ]
         )
     }
 
+    #[test]
+    fn test_puzzle_day1_parse_error() {
+        let puzzle = Day1;
+        let err = puzzle.parse("L68\nX30\nR4x").unwrap_err();
+        assert_eq!(err.message, "unexpected rotation in \"X30\"");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 1)));
+
+        let err = puzzle.parse("L68\nR4x").unwrap_err();
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 2)));
+    }
+
     #[test]
     fn test_puzzle_day1_part1() {
         let puzzle = Day1;
-        assert_eq!(puzzle.part1(&TESTCASE), 3);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 3);
     }
 
     #[test]
     fn test_puzzle_day1_part2() {
         let puzzle = Day1;
-        assert_eq!(puzzle.part2(&TESTCASE), 6);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 6);
     }
 }
@@ -1,10 +1,11 @@
 use std::{collections::HashMap, vec};
 
-use super::Puzzle;
+use super::{parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day11;
 
-struct Device {
+pub struct Device {
     name: String,
     outputs: Vec<String>,
 }
@@ -42,6 +43,16 @@ mod parser {
 }
 
 impl Day11 {
+    fn device<'a>(
+        &self,
+        server_rack: &'a HashMap<String, DeviceTreeNode>,
+        name: &str,
+    ) -> Result<&'a DeviceTreeNode, PuzzleError> {
+        server_rack
+            .get(name)
+            .ok_or_else(|| PuzzleError::new(format!("no device named {:?}", name)))
+    }
+
     fn create_server_rack(&self, devices: &Vec<Device>) -> HashMap<String, DeviceTreeNode> {
         let mut server_rack = HashMap::new();
 
@@ -128,41 +139,7 @@ impl Day11 {
     }
 }
 
-impl Puzzle for Day11 {
-    type Output = i64;
-
-    fn part1(&self, input: &str) -> Self::Output {
-        let devices = parser::parse(input).unwrap().1;
-
-        let server_rack = self.create_server_rack(&devices);
-        let root = server_rack.get("you").unwrap();
-        let out = server_rack.get("out").unwrap();
-
-        self.find_path_count(root, out, &server_rack, &mut HashMap::new())
-    }
-
-    fn part2(&self, input: &str) -> Self::Output {
-        let devices = parser::parse(input).unwrap().1;
-        let server_rack = self.create_server_rack(&devices);
-        let root = server_rack.get("svr").unwrap();
-        let out = server_rack.get("out").unwrap();
-        let mut memo = HashMap::new();
-        self.find_path_count_with_flags(root, out, &server_rack, false, false, &mut memo)
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 11 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 11 Part 2:  {:#?}", ans2);
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TESTCASE: &'static str = r"aaa: you hhh
+const TESTCASE: &str = r"aaa: you hhh
 you: bbb ccc
 bbb: ddd eee
 ccc: ddd eee fff
@@ -173,7 +150,7 @@ ggg: out
 hhh: ccc fff iii
 iii: out";
 
-    const TESTCASE2: &'static str = r"svr: aaa bbb
+const TESTCASE2: &str = r"svr: aaa bbb
 aaa: fft
 fft: ccc
 bbb: tty
@@ -187,6 +164,48 @@ fff: ggg hhh
 ggg: out
 hhh: out";
 
+impl Puzzle for Day11 {
+    type Parsed<'a> = Input;
+    type Output = i64;
+
+    const EXAMPLES: &'static [Example] = &[
+        Example {
+            input: TESTCASE,
+            part1: Some("5"),
+            part2: None,
+        },
+        Example {
+            input: TESTCASE2,
+            part1: None,
+            part2: Some("2"),
+        },
+    ];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        parse_all(input, parser::parse)
+    }
+
+    fn part1(&self, devices: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let server_rack = self.create_server_rack(devices);
+        let root = self.device(&server_rack, "you")?;
+        let out = self.device(&server_rack, "out")?;
+
+        Ok(self.find_path_count(root, out, &server_rack, &mut HashMap::new()))
+    }
+
+    fn part2(&self, devices: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let server_rack = self.create_server_rack(devices);
+        let root = self.device(&server_rack, "svr")?;
+        let out = self.device(&server_rack, "out")?;
+        let mut memo = HashMap::new();
+        Ok(self.find_path_count_with_flags(root, out, &server_rack, false, false, &mut memo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
     #[test]
     fn test_puzzle_day11_parse() {
         let (_, input) = parser::parse(TESTCASE).unwrap();
@@ -199,13 +218,15 @@ hhh: out";
     fn test_puzzle_day11_part1() {
         let puzzle = Day11;
 
-        assert_eq!(puzzle.part1(TESTCASE), 5);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 5);
     }
 
     #[test]
     fn test_puzzle_day11_part2() {
         let puzzle = Day11;
 
-        assert_eq!(puzzle.part2(TESTCASE2), 2);
+        let input = puzzle.parse(TESTCASE2).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 2);
     }
 }
@@ -1,4 +1,5 @@
-use super::Puzzle;
+use super::{parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day2;
 
@@ -70,39 +71,42 @@ impl Day2 {
     }
 }
 
+const TESTCASE: &str = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
 impl Puzzle for Day2 {
+    type Parsed<'a> = Input;
     type Output = i64;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (_, range_list) = parser::parse(input).unwrap();
-        range_list
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("1227775554"),
+        part2: Some("4174379265"),
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        parse_all(input, parser::parse)
+    }
+
+    fn part1(&self, range_list: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(range_list
             .iter()
             .map(|(start, end)| {
                 (*start..=*end)
                     .filter(|&n| !self.check_by_repeat_2(n))
                     .sum::<i64>()
             })
-            .sum()
+            .sum())
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (_, range_list) = parser::parse(input).unwrap();
-        range_list
+    fn part2(&self, range_list: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(range_list
             .iter()
             .map(|(start, end)| {
                 (*start..=*end)
                     .filter(|&n| !self.check_by_repeat_n(n))
                     .sum::<i64>()
             })
-            .sum()
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 2 Part 1:  {:#?}", ans1);
-
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 2 Part 2:  {:#?}", ans2);
+            .sum())
     }
 }
 
@@ -110,8 +114,6 @@ impl Puzzle for Day2 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-
     #[test]
     fn test_puzzle_day2_parse() {
         let input = parser::parse(TESTCASE).unwrap().1;
@@ -143,12 +145,14 @@ mod tests {
     #[test]
     fn test_puzzle_day2_part1() {
         let puzzle = Day2;
-        assert_eq!(puzzle.part1(&TESTCASE), 1227775554);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 1227775554);
     }
 
     #[test]
     fn test_puzzle_day2_part2() {
         let puzzle = Day2;
-        assert_eq!(puzzle.part2(&TESTCASE), 4174379265);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 4174379265);
     }
 }
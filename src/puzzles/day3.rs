@@ -1,4 +1,5 @@
-use super::Puzzle;
+use super::{parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day3;
 
@@ -12,20 +13,34 @@ mod parser {
     pub fn parse(input: &str) -> IResult<&str, Input<'_>> {
         nom::multi::separated_list1(
             nom::character::complete::newline,
-            nom::character::complete::alphanumeric1,
+            nom::character::complete::digit1,
         )(input)
     }
 }
 
 impl Day3 {}
 
+const TESTCASE: &str = r"987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
 impl Puzzle for Day3 {
+    type Parsed<'a> = Input<'a>;
     type Output = u128;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let battery_groups = parser::parse(input).unwrap().1;
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("357"),
+        part2: Some("3121910778619"),
+    }];
 
-        battery_groups
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        parse_all(input, parser::parse)
+    }
+
+    fn part1(&self, battery_groups: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(battery_groups
             .iter()
             .map(|&batteries| {
                 let [max1, max2] = batteries.chars().enumerate().fold([0, 0], |acc, cur| {
@@ -45,32 +60,25 @@ impl Puzzle for Day3 {
 
                 max1 * 10 + max2
             })
-            .sum()
+            .sum())
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let battery_groups = parser::parse(input).unwrap().1;
-        battery_groups
+    fn part2(&self, battery_groups: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(battery_groups
             .iter()
             .map(|&batteries| {
-                let mut joltages = vec![0; 12];
-                let num_joltages = 12;
+                let mut joltages = [0; 12];
+                let num_joltages: usize = 12;
                 let num_battery = batteries.len();
                 for (idx, battery) in batteries.chars().enumerate() {
                     let battery_val = battery.to_digit(10).unwrap() as u128;
-                    let start = if num_joltages > (num_battery - idx) {
-                        num_joltages - (num_battery - idx)
-                    } else {
-                        0
-                    };
+                    let start = num_joltages.saturating_sub(num_battery - idx);
                     let end = idx.min(num_joltages - 1);
 
                     for joltage_idx in start..=end {
                         if battery_val > joltages[joltage_idx] {
                             joltages[joltage_idx] = battery_val;
-                            for i in joltage_idx + 1..=end {
-                                joltages[i] = 0;
-                            }
+                            joltages[joltage_idx + 1..=end].fill(0);
                             break;
                         }
                     }
@@ -78,15 +86,7 @@ impl Puzzle for Day3 {
 
                 joltages.iter().fold(0, |acc, cur| acc * 10 + cur)
             })
-            .sum()
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 3 Part 1:  {:#?}", ans1);
-
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 3 Part 2:  {:#?}", ans2);
+            .sum())
     }
 }
 
@@ -94,11 +94,6 @@ impl Puzzle for Day3 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"987654321111111
-811111111111119
-234234234234278
-818181911112111";
-
     #[test]
     fn test_puzzle_day3_parse() {
         let input = parser::parse(TESTCASE).unwrap().1;
@@ -116,12 +111,14 @@ mod tests {
     #[test]
     fn test_puzzle_day3_part1() {
         let puzzle = Day3;
-        assert_eq!(puzzle.part1(&TESTCASE), 357);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 357);
     }
 
     #[test]
     fn test_puzzle_day3_part2() {
         let puzzle = Day3;
-        assert_eq!(puzzle.part2(&TESTCASE), 3121910778619);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 3121910778619);
     }
 }
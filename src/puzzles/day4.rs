@@ -1,4 +1,5 @@
-use super::Puzzle;
+use super::{check_rows, parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day4;
 
@@ -62,7 +63,7 @@ impl Day4 {
             })
         });
 
-        if to_remove.len() > 0 {
+        if !to_remove.is_empty() {
             Some(to_remove)
         } else {
             None
@@ -70,17 +71,42 @@ impl Day4 {
     }
 }
 
+const TESTCASE: &str = r"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
 impl Puzzle for Day4 {
+    type Parsed<'a> = Input;
     type Output = usize;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (_, grid) = parser::parse(input).unwrap();
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("13"),
+        part2: Some("43"),
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        let grid = parse_all(input, parser::parse)?;
+        check_rows(input, input.trim_end().lines(), |row| row.chars().count())?;
 
-        self.find_remove_pos(&grid).unwrap().len()
+        Ok(grid)
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (_, mut grid) = parser::parse(input).unwrap();
+    fn part1(&self, grid: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        Ok(self
+            .find_remove_pos(grid)
+            .map_or(0, |to_remove| to_remove.len()))
+    }
+
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let mut grid = input.clone();
         let mut remove_count = 0;
 
         while let Some(to_remove) = self.find_remove_pos(&grid) {
@@ -91,14 +117,7 @@ impl Puzzle for Day4 {
             })
         }
 
-        remove_count
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 4 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 4 Part 2:  {:#?}", ans2);
+        Ok(remove_count)
     }
 }
 
@@ -106,17 +125,6 @@ impl Puzzle for Day4 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.";
-
     #[test]
     fn test_puzzle_day4_parse() {
         let input = parser::parse(TESTCASE).unwrap().1;
@@ -137,15 +145,24 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_puzzle_day4_parse_error() {
+        let err = Day4.parse("..@\n@\n").unwrap_err();
+        assert_eq!(err.message, "row has 1 columns, but the first row has 3");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 1)));
+    }
+
     #[test]
     fn test_puzzle_day4_part1() {
         let puzzle = Day4;
-        assert_eq!(puzzle.part1(TESTCASE), 13);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 13);
     }
 
     #[test]
     fn test_puzzle_day4_part2() {
         let puzzle = Day4;
-        assert_eq!(puzzle.part2(TESTCASE), 43);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 43);
     }
 }
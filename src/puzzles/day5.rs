@@ -1,10 +1,11 @@
-use super::Puzzle;
+use super::{parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day5;
 
 type IngredientRange = Vec<(i64, i64)>;
 type IngredientIDs = Vec<i64>;
-struct Input {
+pub struct Input {
     ingredient_range: IngredientRange,
     ingredient_ids: IngredientIDs,
 }
@@ -52,7 +53,8 @@ mod parser {
 }
 
 impl Day5 {
-    fn merge_ingredient_range(&self, ingredient_range: &mut IngredientRange) -> IngredientRange {
+    fn merge_ingredient_range(&self, ingredient_range: &[(i64, i64)]) -> IngredientRange {
+        let mut ingredient_range = ingredient_range.to_vec();
         ingredient_range.sort_by(|a, b| {
             if a.0 == b.0 {
                 a.1.cmp(&b.1)
@@ -62,7 +64,7 @@ impl Day5 {
         });
 
         let mut merged_range: IngredientRange = vec![];
-        for (start, end) in ingredient_range {
+        for (start, end) in &ingredient_range {
             if let Some(last_range) = merged_range.pop() {
                 if *start <= last_range.1 {
                     merged_range.push((last_range.0, last_range.1.max(*end)));
@@ -79,21 +81,37 @@ impl Day5 {
     }
 }
 
+const TESTCASE: &str = r"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
 impl Puzzle for Day5 {
+    type Parsed<'a> = Input;
     type Output = i64;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (
-            _,
-            Input {
-                mut ingredient_range,
-                ingredient_ids,
-            },
-        ) = parser::parse(input).unwrap();
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("3"),
+        part2: Some("14"),
+    }];
 
-        let merge_ingredient_range = self.merge_ingredient_range(&mut ingredient_range);
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        parse_all(input, parser::parse)
+    }
+
+    fn part1(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let merge_ingredient_range = self.merge_ingredient_range(&input.ingredient_range);
 
-        ingredient_ids
+        Ok(input
+            .ingredient_ids
             .iter()
             .filter(|&id| {
                 merge_ingredient_range
@@ -102,30 +120,15 @@ impl Puzzle for Day5 {
                     .count()
                     > 0
             })
-            .count() as i64
+            .count() as i64)
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (
-            _,
-            Input {
-                mut ingredient_range,
-                ingredient_ids: _,
-            },
-        ) = parser::parse(input).unwrap();
-
-        let merge_ingredient_range = self.merge_ingredient_range(&mut ingredient_range);
-        merge_ingredient_range
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let merge_ingredient_range = self.merge_ingredient_range(&input.ingredient_range);
+        Ok(merge_ingredient_range
             .iter()
             .map(|(start, end)| *end - *start + 1)
-            .sum()
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 5 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 5 Part 2:  {:#?}", ans2);
+            .sum())
     }
 }
 
@@ -133,18 +136,6 @@ impl Puzzle for Day5 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
-
     #[test]
     fn test_puzzle_day5_parse() {
         let input = parser::parse(TESTCASE).unwrap().1;
@@ -159,13 +150,15 @@ mod tests {
     fn test_puzzle_day5_part1() {
         let puzzle = Day5;
 
-        assert_eq!(puzzle.part1(TESTCASE), 3);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 3);
     }
 
     #[test]
     fn test_puzzle_day5_part2() {
         let puzzle = Day5;
 
-        assert_eq!(puzzle.part2(TESTCASE), 14);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 14);
     }
 }
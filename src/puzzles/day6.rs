@@ -1,17 +1,33 @@
-use super::Puzzle;
+use super::{check_rows, parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day6;
 
-struct Input {
+#[derive(Debug)]
+pub struct Input {
     nums: Vec<Vec<i64>>,
     ops: Vec<char>,
 }
 
+/// Day 6 reads the worksheet two ways, so both readings are parsed up front.
+#[derive(Debug)]
+pub struct Worksheet {
+    by_rows: Input,
+    by_columns: Input,
+}
+
 mod parser {
     use nom::IResult;
 
     use super::Input;
 
+    fn parse_ops(ops_row: &str) -> IResult<&str, Vec<char>> {
+        nom::combinator::all_consuming(nom::multi::separated_list1(
+            nom::character::complete::multispace1,
+            nom::character::complete::one_of("+*"),
+        ))(ops_row.trim())
+    }
+
     pub fn parse(input: &str) -> IResult<&str, Input> {
         let (_, mut lines) = nom::multi::separated_list1(
             nom::character::complete::newline,
@@ -22,15 +38,16 @@ mod parser {
         let nums = lines
             .iter()
             .map(|&row| {
-                nom::multi::separated_list1(
-                    nom::character::complete::multispace1::<_, nom::error::Error<&str>>,
+                nom::combinator::all_consuming(nom::multi::separated_list1(
+                    nom::character::complete::multispace1,
                     nom::character::complete::i64,
-                )(row.trim())
-                .unwrap()
-                .1
+                ))(row.trim())
+                .map(|(_, row)| row)
             })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .fold(vec![], |mut acc, cur| {
-                if acc.len() == 0 {
+                if acc.is_empty() {
                     for _ in 0..cur.len() {
                         acc.push(vec![]);
                     }
@@ -41,39 +58,40 @@ mod parser {
 
                 acc
             });
-        let ops = nom::multi::separated_list1(
-            nom::character::complete::multispace1::<_, nom::error::Error<&str>>,
-            nom::character::complete::satisfy(|c| !c.is_whitespace()),
-        )(ops_row.trim())
-        .unwrap()
-        .1;
+        let (_, ops) = parse_ops(ops_row)?;
 
         Ok(("", Input { nums, ops }))
     }
 
+    /// The worksheet without surrounding blank lines. Leading spaces are
+    /// kept, as they line up the digits read column by column.
+    pub fn worksheet(input: &str) -> &str {
+        input.trim_start_matches('\n').trim_end()
+    }
+
     pub fn parse_cephalopod(input: &str) -> IResult<&str, Input> {
         let (_, mut lines) = nom::multi::separated_list1(
             nom::character::complete::newline,
             nom::character::complete::not_line_ending,
-        )(input.trim())?;
+        )(worksheet(input))?;
 
         let ops_row = lines.pop().unwrap();
-        let ops = nom::multi::separated_list1(
-            nom::character::complete::multispace1::<_, nom::error::Error<&str>>,
-            nom::character::complete::satisfy(|c| !c.is_whitespace()),
-        )(ops_row.trim())
-        .unwrap()
-        .1
-        .iter()
-        .copied()
-        .rev()
-        .collect();
+        let ops = parse_ops(ops_row)?.1.iter().copied().rev().collect();
+
+        for row in &lines {
+            if let Some(idx) = row.find(|c: char| !c.is_ascii_digit() && c != ' ') {
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    &row[idx..],
+                    nom::error::ErrorKind::Digit,
+                )));
+            }
+        }
 
         let nums_chars_rev = lines
             .iter()
             .map(|row| row.chars().rev().collect::<Vec<char>>())
             .fold(vec![], |mut acc, cur| {
-                if acc.len() == 0 {
+                if acc.is_empty() {
                     for _ in 0..cur.len() {
                         acc.push(vec![]);
                     }
@@ -90,12 +108,9 @@ mod parser {
             .map(|nums| {
                 nums.iter()
                     .map(|num_chars| {
-                        num_chars.iter().fold(0, |acc, cur| {
-                            if *cur == ' ' {
-                                acc
-                            } else {
-                                acc * 10 + cur.to_digit(10).unwrap() as i64
-                            }
+                        num_chars.iter().fold(0, |acc, cur| match cur.to_digit(10) {
+                            Some(digit) => acc * 10 + digit as i64,
+                            None => acc,
                         })
                     })
                     .collect::<Vec<i64>>()
@@ -112,52 +127,72 @@ mod parser {
     }
 }
 
+impl Day6 {
+    fn apply(&self, op: char, arr: &[i64]) -> Result<i64, PuzzleError> {
+        match op {
+            '+' => Ok(arr.iter().sum()),
+            '*' => Ok(arr.iter().product()),
+            _ => Err(PuzzleError::new(format!("unknown operator {:?}", op))),
+        }
+    }
+}
+
+const TESTCASE: &str = r"123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
 impl Puzzle for Day6 {
+    type Parsed<'a> = Worksheet;
     type Output = i64;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (_, Input { nums, ops }) = parser::parse(input).unwrap();
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("4277556"),
+        part2: Some("3263827"),
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        // Both readings transpose the worksheet, so check its shape first.
+        check_rows(input, input.trim().lines(), |row| {
+            row.split_whitespace().count()
+        })?;
+        let by_rows = parse_all(input, parser::parse)?;
+
+        let lines: Vec<&str> = parser::worksheet(input).lines().collect();
+        let numbers = lines.split_last().map_or(&[][..], |(_, numbers)| numbers);
+        check_rows(input, numbers.iter().copied(), |row| row.chars().count())?;
+        let by_columns = parse_all(input, parser::parse_cephalopod)?;
+
+        Ok(Worksheet {
+            by_rows,
+            by_columns,
+        })
+    }
+
+    fn part1(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let Input { nums, ops } = &input.by_rows;
 
         nums.iter()
             .zip(ops)
-            .map(|(arr, op)| match op {
-                '+' => arr.iter().sum::<i64>(),
-                '*' => arr.iter().product::<i64>(),
-                _ => unreachable!(),
-            })
+            .map(|(arr, &op)| self.apply(op, arr))
             .sum()
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (_, Input { nums, ops }) = parser::parse_cephalopod(input).unwrap();
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let Input { nums, ops } = &input.by_columns;
 
         nums.iter()
             .zip(ops)
-            .map(|(arr, op)| match op {
-                '+' => arr.iter().sum::<i64>(),
-                '*' => arr.iter().product::<i64>(),
-                _ => unreachable!(),
-            })
+            .map(|(arr, &op)| self.apply(op, arr))
             .sum()
     }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 6 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 6 Part 2:  {:#?}", ans2);
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  ";
-
     #[test]
     fn test_puzzle_day6_parse() {
         let input = parser::parse(TESTCASE).unwrap().1;
@@ -170,17 +205,36 @@ mod tests {
         assert_eq!(input.ops, vec!['*', '+', '*', '+']);
     }
 
+    #[test]
+    fn test_puzzle_day6_parse_error() {
+        let err = parse_all("1 2\n3 4\n+ %", parser::parse).unwrap_err();
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((3, 2)));
+
+        let err = parse_all("12\n3x\n+ ", parser::parse_cephalopod).unwrap_err();
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 2)));
+
+        let err = Day6.parse("1 2\n1 2 3\n+ +").unwrap_err();
+        assert_eq!(err.message, "row has 3 columns, but the first row has 2");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 1)));
+
+        let err = Day6.parse("12 3\n4 5\n+ +").unwrap_err();
+        assert_eq!(err.message, "row has 3 columns, but the first row has 4");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 1)));
+    }
+
     #[test]
     fn test_puzzle_day6_part1() {
         let puzzle = Day6;
 
-        assert_eq!(puzzle.part1(TESTCASE), 4277556);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 4277556);
     }
 
     #[test]
     fn test_puzzle_day6_part2() {
         let puzzle = Day6;
 
-        assert_eq!(puzzle.part2(TESTCASE), 3263827);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 3263827);
     }
 }
@@ -1,10 +1,12 @@
 use std::collections::{HashMap, HashSet};
 
-use super::Puzzle;
+use super::{check_rows, parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day7;
 
-struct Input {
+#[derive(Debug)]
+pub struct Input {
     enter: usize,
     splitters: Vec<Vec<usize>>,
 }
@@ -20,7 +22,9 @@ mod parser {
             nom::multi::many1(nom::character::complete::satisfy(|c| !c.is_whitespace())),
         )(input)?;
 
-        let enter = lines[0].iter().position(|&x| x == 'S').unwrap();
+        let enter = lines[0].iter().position(|&x| x == 'S').ok_or_else(|| {
+            nom::Err::Failure(nom::error::Error::new(input, nom::error::ErrorKind::Char))
+        })?;
         let splitters = lines[1..]
             .iter()
             .map(|line| {
@@ -45,16 +49,66 @@ mod parser {
     }
 }
 
+const TESTCASE: &str = r"
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
 impl Puzzle for Day7 {
+    type Parsed<'a> = Input;
     type Output = i64;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (_, Input { enter, splitters }) = parser::parse(input).unwrap();
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("21"),
+        part2: Some("40"),
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        let manifold = parse_all(input, parser::parse)?;
+        check_rows(input, input.trim().lines(), |row| row.chars().count())?;
+
+        // A beam split at the edge would leave the manifold.
+        for row in input.trim().lines() {
+            if let Some(edge) = [row.find('^'), row.rfind('^')]
+                .into_iter()
+                .flatten()
+                .find(|&idx| idx == 0 || idx == row.len() - 1)
+            {
+                return Err(PuzzleError::at(
+                    input,
+                    &row[edge..],
+                    "splitter at the edge of the manifold",
+                ));
+            }
+        }
+
+        Ok(manifold)
+    }
+
+    fn part1(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let &Input {
+            enter,
+            ref splitters,
+        } = input;
 
         let mut tachyon = std::collections::HashSet::from([enter]);
         let mut split_times = 0;
 
-        for all_splitters in &splitters {
+        for all_splitters in splitters {
             tachyon = tachyon
                 .iter()
                 .fold(std::collections::HashSet::new(), |mut acc, cur| {
@@ -70,23 +124,25 @@ impl Puzzle for Day7 {
                 });
         }
 
-        split_times
+        Ok(split_times)
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (_, Input { enter, splitters }) = parser::parse(input).unwrap();
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let &Input {
+            enter,
+            ref splitters,
+        } = input;
 
         let last_layers = splitters.len();
         let splitters = splitters
             .iter()
             .enumerate()
-            .map(|(idx, all_splitters)| {
+            .flat_map(|(idx, all_splitters)| {
                 all_splitters
                     .iter()
                     .map(move |&splitter| (idx + 1, splitter))
                     .collect::<Vec<(usize, usize)>>()
             })
-            .flatten()
             .collect::<HashSet<(usize, usize)>>();
         let mut memo = HashMap::new();
 
@@ -117,14 +173,7 @@ impl Puzzle for Day7 {
             res
         }
 
-        split_tachyon(enter, 0, last_layers, &splitters, &mut memo)
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 7 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 7 Part 2:  {:#?}", ans2);
+        Ok(split_tachyon(enter, 0, last_layers, &splitters, &mut memo))
     }
 }
 
@@ -132,24 +181,6 @@ impl Puzzle for Day7 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"
-.......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-
     #[test]
     fn test_puzzle_day7_parse() {
         let (_, input) = parser::parse(TESTCASE).unwrap();
@@ -177,17 +208,29 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_puzzle_day7_parse_error() {
+        let err = Day7.parse("S\n^^^\n.").unwrap_err();
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 1)));
+
+        let err = Day7.parse("..S\n..^\n...").unwrap_err();
+        assert_eq!(err.message, "splitter at the edge of the manifold");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+    }
+
     #[test]
     fn test_puzzle_day7_part1() {
         let puzzle = Day7;
 
-        assert_eq!(puzzle.part1(TESTCASE), 21);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 21);
     }
 
     #[test]
     fn test_puzzle_day7_part2() {
         let puzzle = Day7;
 
-        assert_eq!(puzzle.part2(TESTCASE), 40);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 40);
     }
 }
@@ -1,27 +1,42 @@
-use std::{collections::HashSet, usize};
+use std::collections::HashSet;
 
-use super::Puzzle;
+use super::{parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
-pub struct Day8;
+/// The playground, wiring up the `connections` closest pairs of junction
+/// boxes in part 1.
+pub struct Day8 {
+    pub connections: usize,
+}
 
 type Position = (i64, i64, i64);
 type Input = Vec<Position>;
 
+/// The junction boxes, plus every pair of them ordered by distance.
+pub struct Junctions {
+    positions: Input,
+    pairs_by_dist: Vec<(f64, (usize, usize))>,
+}
+
 mod parser {
     use nom::IResult;
 
     use super::Input;
 
     pub fn parse(input: &str) -> IResult<&str, Input> {
-        nom::combinator::map_res(
-            nom::multi::separated_list1(
-                nom::character::complete::newline,
-                nom::multi::separated_list1(
+        nom::multi::separated_list1(
+            nom::character::complete::newline,
+            nom::sequence::tuple((
+                nom::character::complete::i64,
+                nom::sequence::preceded(
+                    nom::bytes::complete::tag(","),
+                    nom::character::complete::i64,
+                ),
+                nom::sequence::preceded(
                     nom::bytes::complete::tag(","),
                     nom::character::complete::i64,
                 ),
-            ),
-            |res| Ok::<Input, &str>(res.iter().map(|pos| (pos[0], pos[1], pos[2])).collect()),
+            )),
         )(input)
     }
 }
@@ -33,13 +48,8 @@ impl Day8 {
 
         (((a_x - b_x).pow(2) + (a_y - b_y).pow(2) + (a_z - b_z).pow(2)) as f64).sqrt()
     }
-}
-
-impl Puzzle for Day8 {
-    type Output = i64;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (_, junctions) = parser::parse(input).unwrap();
+    fn pairs_by_dist(&self, junctions: &[Position]) -> Vec<(f64, (usize, usize))> {
         let mut all_dist = vec![];
         for i in 0..junctions.len() {
             for j in 0..i {
@@ -47,24 +57,61 @@ impl Puzzle for Day8 {
             }
         }
 
-        let times = {
-            #[cfg(test)]
-            {
-                10
-            }
+        all_dist.sort_by(|a, b| a.0.total_cmp(&b.0));
+        all_dist
+    }
+}
 
-            #[cfg(not(test))]
-            {
-                1000
-            }
-        };
+const TESTCASE: &str = r"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
 
-        all_dist.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+impl Puzzle for Day8 {
+    type Parsed<'a> = Junctions;
+    type Output = i64;
 
-        let mut junction_sets: Vec<HashSet<usize>> = vec![];
-        for idx in 0..times {
-            let (_dist, (junction_a, junction_b)) = all_dist[idx];
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("40"),
+        part2: Some("25272"),
+    }];
 
+    /// The example wires up only 10 pairs.
+    fn for_examples(&self) -> &Self {
+        &Day8 { connections: 10 }
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        let positions = parse_all(input, parser::parse)?;
+        let pairs_by_dist = self.pairs_by_dist(&positions);
+
+        Ok(Junctions {
+            positions,
+            pairs_by_dist,
+        })
+    }
+
+    fn part1(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let mut junction_sets: Vec<HashSet<usize>> = vec![];
+        for &(_dist, (junction_a, junction_b)) in input.pairs_by_dist.iter().take(self.connections)
+        {
             let junction_a_idx = match junction_sets
                 .iter()
                 .position(|junctions| junctions.contains(&junction_a))
@@ -101,30 +148,26 @@ impl Puzzle for Day8 {
             .collect::<Vec<i64>>();
         junction_sets_count.sort_by(|a, b| b.cmp(a));
 
-        junction_sets_count[0..3].iter().product()
+        if junction_sets_count.len() < 3 {
+            return Err(PuzzleError::new(format!(
+                "expected at least 3 circuits, found {}",
+                junction_sets_count.len()
+            )));
+        }
+
+        Ok(junction_sets_count[0..3].iter().product())
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (_, junctions) = parser::parse(input).unwrap();
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let junctions = &input.positions;
         let jucntion_count = junctions.len();
         let mut conn_count = 0;
 
         let mut last_junction_a = 0;
         let mut last_junction_b = 0;
 
-        let mut all_dist = vec![];
-        for i in 0..junctions.len() {
-            for j in 0..i {
-                all_dist.push((self.calc_dist(junctions[i], junctions[j]), (i, j)));
-            }
-        }
-
-        all_dist.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
         let mut junction_sets: Vec<HashSet<usize>> = vec![];
-        for idx in 0..all_dist.len() {
-            let (_dist, (junction_a, junction_b)) = all_dist[idx];
-
+        for &(_dist, (junction_a, junction_b)) in &input.pairs_by_dist {
             let junction_a_idx = match junction_sets
                 .iter()
                 .position(|junctions| junctions.contains(&junction_a))
@@ -163,14 +206,7 @@ impl Puzzle for Day8 {
             }
         }
 
-        junctions[last_junction_a].0 * junctions[last_junction_b].0
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 8 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 8 Part 2:  {:#?}", ans2);
+        Ok(junctions[last_junction_a].0 * junctions[last_junction_b].0)
     }
 }
 
@@ -178,27 +214,6 @@ impl Puzzle for Day8 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689";
-
     #[test]
     fn test_puzzle_day8_parse() {
         let (_, input) = parser::parse(TESTCASE).unwrap();
@@ -232,15 +247,17 @@ mod tests {
 
     #[test]
     fn test_puzzle_day8_part1() {
-        let puzzle = Day8;
+        let puzzle = Day8 { connections: 10 };
 
-        assert_eq!(puzzle.part1(TESTCASE), 40);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 40);
     }
 
     #[test]
     fn test_puzzle_day8_part2() {
-        let puzzle = Day8;
+        let puzzle = Day8 { connections: 1000 };
 
-        assert_eq!(puzzle.part2(TESTCASE), 25272);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 25272);
     }
 }
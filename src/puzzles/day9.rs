@@ -1,5 +1,5 @@
-use super::Puzzle;
-use std::io::Write;
+use super::{parse_all, Example, Puzzle};
+use crate::error::PuzzleError;
 
 pub struct Day9;
 
@@ -28,7 +28,7 @@ impl Day9 {
         ((point_a.0 - point_b.0).abs() + 1) * ((point_a.1 - point_b.1).abs() + 1)
     }
 
-    fn generate_floor(&self, tiles: &Vec<Position>) -> Vec<Vec<bool>> {
+    fn generate_floor(&self, tiles: &[Position]) -> Vec<Vec<bool>> {
         let mut max_row = 0;
         let mut max_col = 0;
         for tile in tiles {
@@ -41,7 +41,7 @@ impl Day9 {
         vec![vec![false; floor_col]; floor_row]
     }
 
-    fn add_border(&self, floor: &mut Vec<Vec<bool>>, tiles: &Vec<Position>) {
+    fn add_border(&self, floor: &mut [Vec<bool>], tiles: &[Position]) {
         for i in 0..tiles.len() {
             let (x1, y1) = tiles[i];
             let (x2, y2) = tiles[(i + 1) % tiles.len()];
@@ -52,18 +52,16 @@ impl Day9 {
             let ymax = y1.max(y2) as usize;
 
             if x1 == x2 {
-                for row in ymin..=ymax {
-                    floor[row][xmin] = true;
+                for row in &mut floor[ymin..=ymax] {
+                    row[xmin] = true;
                 }
             } else {
-                for col in xmin..=xmax {
-                    floor[ymin][col] = true;
-                }
+                floor[ymin][xmin..=xmax].fill(true);
             }
         }
     }
 
-    fn fill_tiles(&self, floor: &mut Vec<Vec<bool>>, tiles: &Vec<Position>) {
+    fn fill_tiles(&self, floor: &mut [Vec<bool>], tiles: &[Position]) {
         // Integer scanline fill: for each integer row y, compute intersections of
         // polygon edges with the horizontal line y, include horizontal edges that
         // lie on that y (push both endpoints), sort intersections and fill between
@@ -74,7 +72,7 @@ impl Day9 {
         }
         let cols = floor[0].len();
 
-        for row in 0..rows {
+        for (row, floor_row) in floor.iter_mut().enumerate() {
             let y = row as i64;
             let mut xs: Vec<f64> = Vec::new();
 
@@ -119,9 +117,7 @@ impl Day9 {
                     let start = start_col.max(0) as usize;
                     let end = end_col.min((cols as isize) - 1).max(0) as usize;
                     if start <= end {
-                        for c in start..=end {
-                            floor[row][c] = true;
-                        }
+                        floor_row[start..=end].fill(true);
                     }
                 }
 
@@ -129,32 +125,43 @@ impl Day9 {
             }
         }
     }
-
-    fn visualize_floor(&self, floor: &Vec<Vec<bool>>) {
-        let outputs_path = std::path::Path::new("./puzzle_inputs").join("day9_output.txt");
-        let mut output_file = std::fs::File::create(outputs_path).unwrap();
-
-        for row in floor {
-            let mut row_str = String::new();
-            for tile in row {
-                if *tile {
-                    row_str += "#";
-                } else {
-                    row_str += ".";
-                }
-            }
-            // println!("{}", row_str);
-
-            writeln!(output_file, "{:}", row_str).unwrap();
-        }
-    }
 }
 
+const TESTCASE: &str = r"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
 impl Puzzle for Day9 {
+    type Parsed<'a> = Input;
     type Output = i64;
 
-    fn part1(&self, input: &str) -> Self::Output {
-        let (_, tiles) = parser::parse(input).unwrap();
+    const EXAMPLES: &'static [Example] = &[Example {
+        input: TESTCASE,
+        part1: Some("50"),
+        part2: Some("24"),
+    }];
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError> {
+        let tiles = parse_all(input, parser::parse)?;
+
+        // Coordinates index the floor in part 2.
+        if let Some(idx) = input.find('-') {
+            return Err(PuzzleError::at(
+                input,
+                &input[idx..],
+                "coordinates cannot be negative",
+            ));
+        }
+
+        Ok(tiles)
+    }
+
+    fn part1(&self, tiles: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
         let mut max_area = 0;
 
         for i in 0..tiles.len() {
@@ -163,15 +170,13 @@ impl Puzzle for Day9 {
             }
         }
 
-        max_area
+        Ok(max_area)
     }
 
-    fn part2(&self, input: &str) -> Self::Output {
-        let (_, tiles) = parser::parse(input).unwrap();
-        let mut floor = self.generate_floor(&tiles);
-        self.add_border(&mut floor, &tiles);
-        self.fill_tiles(&mut floor, &tiles);
-        self.visualize_floor(&floor);
+    fn part2(&self, tiles: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError> {
+        let mut floor = self.generate_floor(tiles);
+        self.add_border(&mut floor, tiles);
+        self.fill_tiles(&mut floor, tiles);
 
         let mut max_area = 0;
         for i in 0..tiles.len() {
@@ -184,19 +189,9 @@ impl Puzzle for Day9 {
                 let xmax = tile_i.0.max(tile_j.0) as usize;
                 let ymax = tile_i.1.max(tile_j.1) as usize;
 
-                let mut is_valid = true;
-                for row in ymin..=ymax {
-                    for col in xmin..=xmax {
-                        if !floor[row][col] {
-                            is_valid = false;
-                            break;
-                        }
-                    }
-
-                    if !is_valid {
-                        break;
-                    }
-                }
+                let is_valid = floor[ymin..=ymax]
+                    .iter()
+                    .all(|row| row[xmin..=xmax].iter().all(|&tile| tile));
 
                 if is_valid {
                     max_area = max_area.max(self.count_area(tiles[i], tiles[j]));
@@ -204,14 +199,7 @@ impl Puzzle for Day9 {
             }
         }
 
-        max_area
-    }
-
-    fn solve(&self, input: &str) {
-        let ans1 = self.part1(&input);
-        println!("Answer of Day 9 Part 1:  {:#?}", ans1);
-        let ans2 = self.part2(&input);
-        println!("Answer of Day 9 Part 2:  {:#?}", ans2);
+        Ok(max_area)
     }
 }
 
@@ -219,16 +207,7 @@ impl Puzzle for Day9 {
 mod tests {
     use super::*;
 
-    const TESTCASE: &'static str = r"7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
-
-    const _TESTCASE2: &'static str = r"7,1
+    const _TESTCASE2: &str = r"7,1
 11,1
 11,7
 9,7
@@ -260,17 +239,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_puzzle_day9_parse_error() {
+        let err = Day9.parse("7,1\n2,-3").unwrap_err();
+        assert_eq!(err.message, "coordinates cannot be negative");
+        assert_eq!(err.location.map(|l| (l.line, l.column)), Some((2, 3)));
+    }
+
     #[test]
     fn test_puzzle_day9_part1() {
         let puzzle = Day9;
 
-        assert_eq!(puzzle.part1(TESTCASE), 50);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part1(&input).unwrap(), 50);
     }
 
     #[test]
     fn test_puzzle_day9_part2() {
         let puzzle = Day9;
 
-        assert_eq!(puzzle.part2(TESTCASE), 24);
+        let input = puzzle.parse(TESTCASE).unwrap();
+        assert_eq!(puzzle.part2(&input).unwrap(), 24);
     }
 }
@@ -1,3 +1,7 @@
+use nom::IResult;
+
+use crate::{answer::Answer, error::PuzzleError};
+
 pub mod day1;
 pub mod day2;
 pub mod day3;
@@ -10,11 +14,211 @@ pub mod day9;
 pub mod day10;
 pub mod day11;
 
+/// An example input from the puzzle description, with the answers it states.
+/// A part without a stated answer is not checked against this input.
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
 pub trait Puzzle {
-    type Output;
+    /// The input in the shape both parts work on. It may borrow from the
+    /// raw input text.
+    type Parsed<'a>;
+    type Output: Into<Answer>;
+
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// The puzzle as set up for [`Self::EXAMPLES`]. A day whose description
+    /// solves its examples with other parameters than the real input returns
+    /// a puzzle set up for them.
+    fn for_examples(&self) -> &Self {
+        self
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Parsed<'a>, PuzzleError>;
+    fn part1(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError>;
+    fn part2(&self, input: &Self::Parsed<'_>) -> Result<Self::Output, PuzzleError>;
+}
+
+/// Runs a day's nom parser over `input`. Parse failures and anything but
+/// whitespace left over afterwards become a [`PuzzleError`] pointing at the
+/// offending text.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl FnOnce(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, PuzzleError> {
+    let (rest, parsed) = parser(input).map_err(|err| PuzzleError::from_nom(input, err))?;
+
+    if !rest.trim().is_empty() {
+        let rest = rest.trim_start();
+        return Err(PuzzleError::at(input, rest, "unexpected trailing input"));
+    }
+
+    Ok(parsed)
+}
+
+/// Checks that every row is as wide as the first, as measured by `width`, so
+/// that a grid read from `input` can be indexed freely. `rows` must be
+/// slices of `input`; a row of the wrong width becomes a [`PuzzleError`]
+/// pointing at it.
+pub fn check_rows<'a>(
+    input: &str,
+    rows: impl IntoIterator<Item = &'a str>,
+    width: impl Fn(&str) -> usize,
+) -> Result<(), PuzzleError> {
+    let mut rows = rows.into_iter();
+    let Some(first) = rows.next() else {
+        return Ok(());
+    };
+
+    let expected = width(first);
+    for row in rows {
+        let found = width(row);
+        if found != expected {
+            return Err(PuzzleError::at(
+                input,
+                row,
+                format!(
+                    "row has {} columns, but the first row has {}",
+                    found, expected
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Object-safe view of a [`Puzzle`], so that days with different `Output`
+/// types can live side by side in [`PUZZLES`].
+pub trait Solver: Sync {
+    /// Parses `input` once; both parts can then be run on the result.
+    fn parse<'a>(&'a self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, PuzzleError>;
+
+    fn examples(&self) -> &'static [Example];
+
+    /// The solver to check [`Solver::examples`] with.
+    fn example_solver(&self) -> &dyn Solver;
+
+    fn part1(&self, input: &str) -> Result<Answer, PuzzleError> {
+        self.parse(input)?.part1()
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, PuzzleError> {
+        self.parse(input)?.part2()
+    }
+
+    /// Solves both parts; printing them is up to the caller.
+    fn solve(&self, input: &str) -> Result<[Answer; 2], PuzzleError> {
+        let parsed = self.parse(input)?;
+        Ok([parsed.part1()?, parsed.part2()?])
+    }
+}
+
+/// A day's input after [`Solver::parse`], ready to have either part run on it.
+pub trait Parsed {
+    fn part1(&self) -> Result<Answer, PuzzleError>;
+    fn part2(&self) -> Result<Answer, PuzzleError>;
+}
+
+struct ParsedInput<'a, P: Puzzle> {
+    puzzle: &'a P,
+    parsed: P::Parsed<'a>,
+}
+
+impl<P: Puzzle> Parsed for ParsedInput<'_, P> {
+    fn part1(&self) -> Result<Answer, PuzzleError> {
+        self.puzzle.part1(&self.parsed).map(Into::into)
+    }
+
+    fn part2(&self) -> Result<Answer, PuzzleError> {
+        self.puzzle.part2(&self.parsed).map(Into::into)
+    }
+}
+
+impl<P> Solver for P
+where
+    P: Puzzle + Sync,
+{
+    fn parse<'a>(&'a self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, PuzzleError> {
+        Ok(Box::new(ParsedInput {
+            puzzle: self,
+            parsed: Puzzle::parse(self, input)?,
+        }))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        P::EXAMPLES
+    }
+
+    fn example_solver(&self) -> &dyn Solver {
+        self.for_examples()
+    }
+}
+
+/// Every implemented day, in day order. `aoc2025 new` appends to this table.
+pub static PUZZLES: &[(i32, &dyn Solver)] = &[
+    (1, &day1::Day1),
+    (2, &day2::Day2),
+    (3, &day3::Day3),
+    (4, &day4::Day4),
+    (5, &day5::Day5),
+    (6, &day6::Day6),
+    (7, &day7::Day7),
+    (8, &day8::Day8 { connections: 1000 }),
+    (9, &day9::Day9),
+    (10, &day10::Day10),
+    (11, &day11::Day11),
+];
+
+pub fn find(day: i32) -> Option<&'static dyn Solver> {
+    PUZZLES
+        .iter()
+        .find(|(registered_day, _)| *registered_day == day)
+        .map(|(_, puzzle)| *puzzle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered_by_day() {
+        assert!(PUZZLES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
 
-    fn part1(&self, input: &str) -> Self::Output;
-    fn part2(&self, input: &str) -> Self::Output;
+    #[test]
+    fn test_registry_find() {
+        assert!(find(1).is_some());
+        assert!(find(11).is_some());
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
 
-    fn solve(&self, input: &str);
+    #[test]
+    fn test_registry_examples() {
+        for (day, puzzle) in PUZZLES {
+            let solver = puzzle.example_solver();
+            for example in puzzle.examples() {
+                if let Some(expected) = example.part1 {
+                    assert_eq!(
+                        solver.part1(example.input).unwrap().to_string(),
+                        expected,
+                        "day {} part 1",
+                        day
+                    );
+                }
+                if let Some(expected) = example.part2 {
+                    assert_eq!(
+                        solver.part2(example.input).unwrap().to_string(),
+                        expected,
+                        "day {} part 2",
+                        day
+                    );
+                }
+            }
+        }
+    }
 }
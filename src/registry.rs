@@ -0,0 +1,225 @@
+use syn::{spanned::Spanned, Expr, Item, Lit};
+
+/// The registration of one day in `src/puzzles/mod.rs`, with the line it
+/// ends on (1-based).
+struct Entry {
+    day: i32,
+    line: usize,
+}
+
+/// Registers `day` in the source of `src/puzzles/mod.rs`: its `pub mod dayN;`
+/// declaration and its `PUZZLES` entry, each inserted in day order. Returns
+/// `None` if the day is already registered.
+///
+/// The file is parsed rather than searched for patterns. Anything about the
+/// declarations or the table that does not look like what `new` generates is
+/// an error, and the file is left alone.
+pub fn register_day(source: &str, day: i32) -> Result<Option<String>, String> {
+    let file = syn::parse_file(source).map_err(|e| format!("cannot parse it: {}", e))?;
+    let module = format!("day{}", day);
+
+    let mut mods = vec![];
+    let mut table = None;
+    for item in &file.items {
+        match item {
+            Item::Mod(item) => {
+                let Some(mod_day) = day_number(&item.ident.to_string()) else {
+                    continue;
+                };
+                if item.content.is_some() || !matches!(item.vis, syn::Visibility::Public(_)) {
+                    return Err(format!(
+                        "`mod day{}` is not a `pub mod day{};`",
+                        mod_day, mod_day
+                    ));
+                }
+                mods.push(Entry {
+                    day: mod_day,
+                    line: item.span().end().line,
+                });
+            }
+            Item::Static(item) if item.ident == "PUZZLES" => {
+                table = Some(table_entries(&item.expr)?)
+            }
+            _ => {}
+        }
+    }
+    let (entries, table_end) = table.ok_or("no `PUZZLES` table")?;
+    if mods.is_empty() {
+        return Err("no `pub mod dayN;` declarations".to_string());
+    }
+
+    let mut inserts = vec![];
+    if !mods.iter().any(|entry| entry.day == day) {
+        inserts.push((insert_after(&mods, day), format!("pub mod {};", module)));
+    }
+    if !entries.iter().any(|entry| entry.day == day) {
+        let line = match entries.last() {
+            Some(_) => insert_after(&entries, day),
+            None => table_end - 1,
+        };
+        inserts.push((line, format!("    ({}, &{}::Day{}),", day, module, day)));
+    }
+    if inserts.is_empty() {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<String> = source.split_inclusive('\n').map(str::to_string).collect();
+    inserts.sort_by_key(|(line, _)| *line);
+    for (line, text) in inserts.into_iter().rev() {
+        let text = text + "\n";
+        if line == 0 {
+            lines.insert(0, text);
+        } else {
+            if !lines[line - 1].ends_with('\n') {
+                lines[line - 1].push('\n');
+            }
+            lines.insert(line, text);
+        }
+    }
+
+    let registered = lines.concat();
+    syn::parse_file(&registered).map_err(|e| format!("registering would break it: {}", e))?;
+    Ok(Some(registered))
+}
+
+fn day_number(name: &str) -> Option<i32> {
+    let digits = name.strip_prefix("day")?;
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// The line after which `day` goes, so that `entries` stay in day order:
+/// after the last earlier day, or before the first one if `day` comes first.
+fn insert_after(entries: &[Entry], day: i32) -> usize {
+    match entries.iter().rfind(|entry| entry.day < day) {
+        Some(entry) => entry.line,
+        None => entries[0].line - 1,
+    }
+}
+
+/// The entries of `&[(N, &dayN::DayN), ...]`, which must each be on a line
+/// of their own, and the line of the closing `]`.
+fn table_entries(expr: &Expr) -> Result<(Vec<Entry>, usize), String> {
+    let Expr::Reference(reference) = expr else {
+        return Err("`PUZZLES` is not a `&[...]` table".to_string());
+    };
+    let Expr::Array(array) = reference.expr.as_ref() else {
+        return Err("`PUZZLES` is not a `&[...]` table".to_string());
+    };
+
+    let mut entries: Vec<Entry> = vec![];
+    for elem in &array.elems {
+        let day = table_entry(elem).ok_or_else(|| {
+            format!(
+                "`PUZZLES` entry on line {} is not `(N, &dayN::DayN)`",
+                elem.span().start().line
+            )
+        })?;
+        let (start, end) = (elem.span().start().line, elem.span().end().line);
+        if entries.last().is_some_and(|last| last.line >= start) {
+            return Err(format!(
+                "`PUZZLES` has more than one entry on line {}",
+                start
+            ));
+        }
+        entries.push(Entry { day, line: end });
+    }
+
+    let table_end = array.bracket_token.span.close().start().line;
+    if entries.last().is_some_and(|last| last.line >= table_end)
+        || array.bracket_token.span.open().start().line >= table_end
+    {
+        return Err("`PUZZLES` does not end with `];` on a line of its own".to_string());
+    }
+
+    Ok((entries, table_end))
+}
+
+/// The day of a `(N, &dayN::DayN)` entry, if that is what `elem` is.
+fn table_entry(elem: &Expr) -> Option<i32> {
+    let Expr::Tuple(tuple) = elem else {
+        return None;
+    };
+    let [Expr::Lit(number), Expr::Reference(solver)] = [tuple.elems.first()?, tuple.elems.last()?]
+    else {
+        return None;
+    };
+    let Lit::Int(number) = &number.lit else {
+        return None;
+    };
+    let Expr::Path(path) = solver.expr.as_ref() else {
+        return None;
+    };
+
+    let day: i32 = number.base10_parse().ok()?;
+    let segments: Vec<String> = path
+        .path
+        .segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect();
+    (tuple.elems.len() == 2 && segments == [format!("day{}", day), format!("Day{}", day)])
+        .then_some(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "use nom::IResult;
+
+pub mod day1;
+pub mod day2;
+pub mod day9;
+pub mod day11;
+
+pub static PUZZLES: &[(i32, &dyn Solver)] = &[
+    (1, &day1::Day1),
+    (2, &day2::Day2),
+    (9, &day9::Day9),
+    (11, &day11::Day11),
+];
+";
+
+    #[test]
+    fn test_register_day_in_order() {
+        let registered = register_day(MOD_RS, 10).unwrap().unwrap();
+
+        assert!(registered.contains("pub mod day9;\npub mod day10;\npub mod day11;\n"));
+        assert!(registered.contains(
+            "    (9, &day9::Day9),\n    (10, &day10::Day10),\n    (11, &day11::Day11),\n];\n"
+        ));
+        assert_eq!(register_day(&registered, 10).unwrap(), None);
+
+        let registered = register_day(MOD_RS, 12).unwrap().unwrap();
+        assert!(registered.contains("pub mod day11;\npub mod day12;\n\n"));
+        assert!(registered.ends_with("    (12, &day12::Day12),\n];\n"));
+
+        let registered = register_day(MOD_RS.trim_end(), 0).unwrap().unwrap();
+        assert!(registered.contains("IResult;\n\npub mod day0;\npub mod day1;\n"));
+        assert!(registered.ends_with("&[\n    (0, &day0::Day0),\n    (1, &day1::Day1),\n    (2, &day2::Day2),\n    (9, &day9::Day9),\n    (11, &day11::Day11),\n];"));
+    }
+
+    #[test]
+    fn test_register_day_completes_partial_registration() {
+        let source = MOD_RS.replace("    (9, &day9::Day9),\n", "");
+        let registered = register_day(&source, 9).unwrap().unwrap();
+
+        assert_eq!(registered, MOD_RS);
+    }
+
+    #[test]
+    fn test_register_day_refuses_unknown_layouts() {
+        assert!(register_day("pub mod day1;\nfn broken( {", 2).is_err());
+        assert!(register_day(
+            &MOD_RS.replace("pub static PUZZLES", "pub static SOLVERS"),
+            3
+        )
+        .is_err());
+        assert!(register_day(&MOD_RS.replace("(2, &day2::Day2)", "(2, &day3::Day3)"), 3).is_err());
+        assert!(register_day(&MOD_RS.replace("::Day1),\n    (2", "::Day1), (2"), 3).is_err());
+        assert!(register_day(&MOD_RS.replace("pub mod day2;", "mod day2;"), 3).is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use serde::Serialize;
+
+use crate::{
+    answer::Answer,
+    error::PuzzleError,
+    runner::{PartRun, Timing},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Parsed,
+    Solved,
+    Skipped,
+    NotFound,
+    Failed,
+    /// An example's answer differs from the one its description states.
+    Mismatch,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Parsed => "parsed",
+            Status::Solved => "solved",
+            Status::Skipped => "skipped",
+            Status::NotFound => "not_found",
+            Status::Failed => "failed",
+            Status::Mismatch => "mismatch",
+        }
+    }
+}
+
+/// One machine-readable result line: a solved part, an example checked
+/// against its stated answer, or a day that could not be run.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    /// Set when results from several profiles are reported together.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    pub day: i32,
+    pub part: Option<u8>,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub answer_type: Option<&'static str>,
+    #[serde(flatten)]
+    pub timing: Option<Timing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// For examples, which one (counting from 1) and its stated answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+}
+
+impl Record {
+    pub fn solved(day: i32, run: &PartRun) -> Record {
+        Record {
+            part: Some(run.part),
+            answer: Some(run.answer.to_string()),
+            answer_type: Some(run.answer.kind()),
+            timing: Some(run.timing),
+            ..Record::unsolved(day, Status::Solved)
+        }
+    }
+
+    /// A part solved on an example: solved if the answer is the stated one,
+    /// a mismatch otherwise.
+    pub fn example(day: i32, example: usize, part: u8, answer: &Answer, expected: &str) -> Record {
+        let status = if answer.to_string() == expected {
+            Status::Solved
+        } else {
+            Status::Mismatch
+        };
+
+        Record {
+            part: Some(part),
+            answer: Some(answer.to_string()),
+            answer_type: Some(answer.kind()),
+            example: Some(example),
+            expected: Some(expected.to_string()),
+            ..Record::unsolved(day, status)
+        }
+    }
+
+    /// The time taken to parse a day's input, shared by both parts.
+    pub fn parsed(day: i32, timing: Timing) -> Record {
+        Record {
+            timing: Some(timing),
+            ..Record::unsolved(day, Status::Parsed)
+        }
+    }
+
+    /// A day that failed to parse (`part` is `None`) or to solve.
+    pub fn failed(day: i32, part: Option<u8>, err: &PuzzleError) -> Record {
+        Record {
+            part,
+            error: Some(err.to_string()),
+            ..Record::unsolved(day, Status::Failed)
+        }
+    }
+
+    pub fn with_profile(self, profile: &str) -> Record {
+        Record {
+            profile: Some(profile.to_string()),
+            ..self
+        }
+    }
+
+    pub fn unsolved(day: i32, status: Status) -> Record {
+        Record {
+            profile: None,
+            day,
+            part: None,
+            status,
+            answer: None,
+            answer_type: None,
+            timing: None,
+            error: None,
+            example: None,
+            expected: None,
+        }
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("records always serialize")
+}
+
+const TSV_HEADER: &str =
+    "day\tpart\tstatus\tanswer\tanswer_type\truns\tmin_ns\tmedian_ns\tmax_ns\terror";
+
+/// Records as tab-separated values, with a leading `profile` column when
+/// any record has a profile, and trailing `example` and `expected` columns
+/// when any record is an example. Backslashes, tabs and line breaks in a
+/// field are written as `\\`, `\t`, `\n` and `\r`, so that every record
+/// stays on one line with the same number of columns.
+pub fn to_tsv(records: &[Record]) -> String {
+    fn field<T: ToString>(value: &Option<T>) -> String {
+        value
+            .as_ref()
+            .map(|v| {
+                v.to_string()
+                    .replace('\\', "\\\\")
+                    .replace('\t', "\\t")
+                    .replace('\n', "\\n")
+                    .replace('\r', "\\r")
+            })
+            .unwrap_or_default()
+    }
+
+    let with_profile = records.iter().any(|record| record.profile.is_some());
+    let with_example = records.iter().any(|record| record.example.is_some());
+    let mut header = TSV_HEADER.to_string();
+    if with_profile {
+        header = format!("profile\t{}", header);
+    }
+    if with_example {
+        header += "\texample\texpected";
+    }
+
+    let mut lines = vec![header];
+    for record in records {
+        let timing = record.timing.as_ref();
+        let profile = with_profile.then(|| field(&record.profile));
+        let example = with_example
+            .then(|| [field(&record.example), field(&record.expected)])
+            .into_iter()
+            .flatten();
+        lines.push(
+            profile
+                .into_iter()
+                .chain([
+                    record.day.to_string(),
+                    field(&record.part),
+                    record.status.as_str().to_string(),
+                    field(&record.answer),
+                    field(&record.answer_type),
+                    field(&timing.map(|t| t.runs)),
+                    field(&timing.map(|t| t.min.as_nanos())),
+                    field(&timing.map(|t| t.median.as_nanos())),
+                    field(&timing.map(|t| t.max.as_nanos())),
+                    field(&record.error),
+                ])
+                .chain(example)
+                .collect::<Vec<_>>()
+                .join("\t"),
+        );
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let run = PartRun {
+            part: 1,
+            answer: Answer::from(733i64),
+            timing: Timing::from_samples(vec![Duration::from_nanos(1500)]),
+        };
+
+        vec![
+            Record::parsed(11, run.timing),
+            Record::solved(11, &run),
+            Record::unsolved(3, Status::Skipped),
+            Record::failed(4, Some(2), &PuzzleError::new("bad input").with_day(4)),
+        ]
+    }
+
+    #[test]
+    fn test_report_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&records())).unwrap();
+
+        assert_eq!(json[0]["status"], "parsed");
+        assert!(json[0]["part"].is_null());
+        assert_eq!(json[0]["median_ns"], 1500);
+        assert_eq!(json[1]["day"], 11);
+        assert_eq!(json[1]["answer"], "733");
+        assert_eq!(json[1]["answer_type"], "i64");
+        assert_eq!(json[1]["median_ns"], 1500);
+        assert_eq!(json[2]["status"], "skipped");
+        assert!(json[2]["answer"].is_null());
+        assert!(json[2].get("median_ns").is_none());
+        assert!(json[2].get("error").is_none());
+        assert_eq!(json[3]["status"], "failed");
+        assert_eq!(json[3]["error"], "day 4: bad input");
+    }
+
+    #[test]
+    fn test_report_tsv() {
+        assert_eq!(
+            to_tsv(&records()),
+            format!(
+                "{}\n11\t\tparsed\t\t\t1\t1500\t1500\t1500\t\n11\t1\tsolved\t733\ti64\t1\t1500\t1500\t1500\t\n3\t\tskipped\t\t\t\t\t\t\t\n4\t2\tfailed\t\t\t\t\t\t\tday 4: bad input",
+                TSV_HEADER
+            )
+        );
+
+        let run = PartRun {
+            part: 2,
+            answer: Answer::from("A\tB\nC\\D"),
+            timing: Timing::from_samples(vec![Duration::from_nanos(1500)]),
+        };
+        let tsv = to_tsv(&[
+            Record::solved(5, &run),
+            Record::failed(6, None, &PuzzleError::new("expected a digit\n  1\tx")),
+        ]);
+        let lines: Vec<_> = tsv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "5\t2\tsolved\tA\\tB\\nC\\\\D\tstring\t1\t1500\t1500\t1500\t"
+        );
+        assert_eq!(
+            lines[2],
+            "6\t\tfailed\t\t\t\t\t\t\texpected a digit\\n  1\\tx"
+        );
+        assert!(lines
+            .iter()
+            .all(|line| line.split('\t').count() == TSV_HEADER.split('\t').count()));
+    }
+
+    #[test]
+    fn test_report_examples() {
+        let examples = vec![
+            Record::example(4, 1, 1, &Answer::from(13i64), "13"),
+            Record::example(4, 1, 2, &Answer::from(42i64), "43"),
+        ];
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&examples)).unwrap();
+        assert_eq!(json[0]["status"], "solved");
+        assert_eq!(json[0]["example"], 1);
+        assert_eq!(json[1]["status"], "mismatch");
+        assert_eq!(json[1]["expected"], "43");
+        assert!(!to_json(&records()).contains("expected"));
+
+        let tsv = to_tsv(&examples);
+        let lines: Vec<_> = tsv.lines().collect();
+        assert_eq!(lines[0], format!("{}\texample\texpected", TSV_HEADER));
+        assert_eq!(lines[2], "4\t2\tmismatch\t42\ti64\t\t\t\t\t\t1\t43");
+    }
+
+    #[test]
+    fn test_report_profiles() {
+        let alice: Vec<_> = records()
+            .into_iter()
+            .take(2)
+            .map(|record| record.with_profile("alice"))
+            .collect();
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&alice)).unwrap();
+        assert_eq!(json[1]["profile"], "alice");
+        assert!(!to_json(&records()).contains("profile"));
+
+        let tsv = to_tsv(&alice);
+        let lines: Vec<_> = tsv.lines().collect();
+        assert_eq!(lines[0], format!("profile\t{}", TSV_HEADER));
+        assert_eq!(
+            lines[2],
+            "alice\t11\t1\tsolved\t733\ti64\t1\t1500\t1500\t1500\t"
+        );
+    }
+}
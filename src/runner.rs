@@ -0,0 +1,156 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::{
+    answer::Answer,
+    error::PuzzleError,
+    puzzles::{Parsed, Solver},
+};
+
+/// Wall-clock statistics over one or more runs of the same phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        assert!(!samples.is_empty(), "timing needs at least one sample");
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+
+        Timing {
+            runs,
+            min: samples[0],
+            median,
+            max: samples[runs - 1],
+        }
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs == 1 {
+            write!(f, "{:.2?}", self.min)
+        } else {
+            write!(
+                f,
+                "min {:.2?} / median {:.2?} / max {:.2?} over {} runs",
+                self.min, self.median, self.max, self.runs
+            )
+        }
+    }
+}
+
+/// Serialized as `runs`, `min_ns`, `median_ns` and `max_ns`.
+impl Serialize for Timing {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Timing", 4)?;
+        state.serialize_field("runs", &self.runs)?;
+        state.serialize_field("min_ns", &self.min.as_nanos())?;
+        state.serialize_field("median_ns", &self.median.as_nanos())?;
+        state.serialize_field("max_ns", &self.max.as_nanos())?;
+        state.end()
+    }
+}
+
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Answer,
+    pub timing: Timing,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+/// Parses `input` for `puzzle` `repeat` times. The parsed input is taken
+/// from the first run; errors are tagged with `day`.
+pub fn run_parse<'a>(
+    puzzle: &'a dyn Solver,
+    day: i32,
+    input: &'a str,
+    repeat: usize,
+) -> Result<(Box<dyn Parsed + 'a>, Timing), PuzzleError> {
+    let (parsed, elapsed) = timed(|| puzzle.parse(input));
+    let parsed = parsed.map_err(|e| e.with_day(day))?;
+    let mut samples = vec![elapsed];
+    for _ in 1..repeat {
+        samples.push(timed(|| puzzle.parse(input)).1);
+    }
+
+    Ok((parsed, Timing::from_samples(samples)))
+}
+
+/// Runs one part on already parsed input `repeat` times. The answer is taken
+/// from the first run; errors are tagged with `day`.
+pub fn run_part(
+    parsed: &dyn Parsed,
+    day: i32,
+    part: u8,
+    repeat: usize,
+) -> Result<PartRun, PuzzleError> {
+    let solve = || match part {
+        1 => parsed.part1(),
+        2 => parsed.part2(),
+        _ => panic!("puzzles only have parts 1 and 2, not {}", part),
+    };
+
+    let (answer, elapsed) = timed(solve);
+    let answer = answer.map_err(|e| e.with_day(day))?;
+    let mut samples = vec![elapsed];
+    for _ in 1..repeat {
+        samples.push(timed(solve).1);
+    }
+
+    Ok(PartRun {
+        part,
+        answer,
+        timing: Timing::from_samples(samples),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_timing_single_sample() {
+        let timing = Timing::from_samples(vec![ms(3)]);
+
+        assert_eq!(timing.runs, 1);
+        assert_eq!(
+            (timing.min, timing.median, timing.max),
+            (ms(3), ms(3), ms(3))
+        );
+        assert_eq!(timing.to_string(), "3.00ms");
+    }
+
+    #[test]
+    fn test_timing_statistics() {
+        let odd = Timing::from_samples(vec![ms(5), ms(1), ms(9)]);
+        assert_eq!((odd.min, odd.median, odd.max), (ms(1), ms(5), ms(9)));
+
+        let even = Timing::from_samples(vec![ms(4), ms(8), ms(2), ms(6)]);
+        assert_eq!((even.min, even.median, even.max), (ms(2), ms(5), ms(8)));
+    }
+}
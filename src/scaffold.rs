@@ -0,0 +1,576 @@
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    description::{PartExample, PuzzleExamples},
+    registry,
+};
+
+/// One entry of a generated `EXAMPLES` table.
+struct ExampleEntry {
+    input_const: &'static str,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Project-local templates, `templates/<name>.rs.tmpl`, take precedence
+/// over the built-in ones of the same name.
+pub const TEMPLATE_DIR: &str = "templates";
+
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Built-in templates by name. Templates are day modules with `{{day}}`,
+/// `{{output}}`, `{{testcases}}`, `{{examples}}`, `{{part1_test}}` and
+/// `{{part2_test}}` placeholders.
+pub const BUILTIN_TEMPLATES: &[(&str, &str)] = &[
+    ("default", include_str!("templates/default.rs.tmpl")),
+    ("grid", include_str!("templates/grid.rs.tmpl")),
+    ("graph", include_str!("templates/graph.rs.tmpl")),
+    ("nom", include_str!("templates/nom.rs.tmpl")),
+];
+
+/// Answer types a generated module can use as its `Output`.
+pub const OUTPUT_TYPES: [&str; 13] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    "String",
+];
+
+pub fn builtin_template(name: &str) -> Option<&'static str> {
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, template)| *template)
+}
+
+/// The template called `name`, from [`TEMPLATE_DIR`] if it is there,
+/// otherwise built in.
+pub fn load_template(name: &str) -> Result<String, Box<dyn Error>> {
+    let path = Path::new(TEMPLATE_DIR).join(format!("{}.rs.tmpl", name));
+    match fs::read_to_string(&path) {
+        Ok(template) => return Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(format!("cannot read {}: {}", path.display(), e).into()),
+    }
+
+    builtin_template(name).map(str::to_string).ok_or_else(|| {
+        let builtins: Vec<_> = BUILTIN_TEMPLATES.iter().map(|(name, _)| *name).collect();
+        format!(
+            "no template {:?}: add {} or use one of {}",
+            name,
+            path.display(),
+            builtins.join(", ")
+        )
+        .into()
+    })
+}
+
+/// Replaces every `{{name}}` in `template` with its value in `values`.
+/// Unknown placeholders are an error, so typos in a template do not end up
+/// in generated code.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String, String> {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let len = rest[start..]
+            .find("}}")
+            .ok_or("unterminated {{ placeholder")?;
+        let name = rest[start + 2..start + len].trim();
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .ok_or_else(|| format!("unknown placeholder {{{{{}}}}}", name))?
+            .1;
+
+        rendered += &rest[..start];
+        rendered += value;
+        rest = &rest[start + len + 2..];
+    }
+
+    Ok(rendered + rest)
+}
+
+/// The source of a new day module rendered from `template`, with whatever
+/// examples the puzzle description provided filled into `TESTCASE`,
+/// `EXAMPLES` and the tests.
+pub fn solution_module(
+    template: &str,
+    day: i32,
+    output: &str,
+    examples: &PuzzleExamples,
+) -> Result<String, String> {
+    let part1 = examples.part1.clone().unwrap_or_default();
+    let part2 = examples.part2.clone().unwrap_or_default();
+    let separate_part2 = needs_own_testcase(&part1, &part2);
+
+    let mut testcases = testcase_const("TESTCASE", part1.input.as_deref().unwrap_or(""));
+    let mut entries = vec![ExampleEntry {
+        input_const: "TESTCASE",
+        part1: part1.answer.clone(),
+        part2: None,
+    }];
+    let part2_const = if separate_part2 {
+        testcases += "\n";
+        testcases += &testcase_const("TESTCASE2", part2.input.as_deref().unwrap_or(""));
+        entries.push(ExampleEntry {
+            input_const: "TESTCASE2",
+            part1: None,
+            part2: part2.answer.clone(),
+        });
+        "TESTCASE2"
+    } else {
+        entries[0].part2 = part2.answer.clone();
+        "TESTCASE"
+    };
+
+    render(
+        template,
+        &[
+            ("day", &day.to_string()),
+            ("output", output),
+            ("testcases", &testcases),
+            ("examples", &examples_const(&entries)),
+            (
+                "part1_test",
+                &part_test(day, 1, "TESTCASE", part1.answer.as_deref(), output),
+            ),
+            (
+                "part2_test",
+                &part_test(day, 2, part2_const, part2.answer.as_deref(), output),
+            ),
+        ],
+    )
+}
+
+/// What `aoc2025 new` will do to the files of a day, decided before any of
+/// them is written.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewDay {
+    pub module_path: PathBuf,
+    /// Whether the module exists and is regenerated.
+    pub overwrite: bool,
+    pub mod_path: PathBuf,
+    /// The registered `mod_path` source, `None` if the day already is
+    /// registered, or why it cannot be registered automatically. The module
+    /// is still written in that last case, to be registered by hand.
+    pub registered: Result<Option<String>, String>,
+}
+
+impl NewDay {
+    /// Plans creating `day` at `module_path`, registering it in the
+    /// `mod_source` read from `mod_path`. An existing module is an error
+    /// unless `force` is given.
+    pub fn plan(
+        day: i32,
+        module_path: &Path,
+        exists: bool,
+        force: bool,
+        mod_path: &Path,
+        mod_source: &str,
+    ) -> Result<NewDay, String> {
+        if exists && !force {
+            return Err(format!(
+                "{} already exists (use --force to regenerate it)",
+                module_path.display()
+            ));
+        }
+
+        let registered = registry::register_day(mod_source, day).map_err(|e| {
+            format!(
+                "cannot register day {d} in {path}: {e}; add `pub mod day{d};` and `({d}, &day{d}::Day{d}),` to it by hand",
+                d = day,
+                path = mod_path.display(),
+                e = e
+            )
+        });
+
+        Ok(NewDay {
+            module_path: module_path.to_path_buf(),
+            overwrite: exists,
+            mod_path: mod_path.to_path_buf(),
+            registered,
+        })
+    }
+
+    /// What the plan would do, one line per file, for `--dry-run`.
+    pub fn dry_run(&self) -> Result<Vec<String>, String> {
+        let action = if self.overwrite {
+            "overwrite"
+        } else {
+            "create"
+        };
+        let registration = match &self.registered {
+            Ok(Some(_)) => format!("Would modify {}", self.mod_path.display()),
+            Ok(None) => format!("Would leave {} unchanged", self.mod_path.display()),
+            Err(e) => return Err(e.clone()),
+        };
+
+        Ok(vec![
+            format!("Would {} {}", action, self.module_path.display()),
+            registration,
+        ])
+    }
+}
+
+/// Adds the part 2 example to a module generated by [`solution_module`]
+/// before part 2 was unlocked. Fails if the parts it needs to change have
+/// been edited since.
+pub fn add_part2_example(source: &str, part2: &PartExample) -> Result<String, String> {
+    let answer = part2
+        .answer
+        .as_deref()
+        .ok_or("the part 2 description has no emphasised answer")?;
+
+    let examples_start = source
+        .find("    const EXAMPLES: &'static [Example] = &[Example {\n")
+        .ok_or("no single-entry EXAMPLES table")?;
+    let examples_end = source[examples_start..]
+        .find("    }];\n")
+        .map(|idx| examples_start + idx + "    }];\n".len())
+        .ok_or("EXAMPLES table is not terminated")?;
+    let examples = &source[examples_start..examples_end];
+    if !examples.contains("        input: TESTCASE,\n") || !examples.contains("part2: None,") {
+        return Err("EXAMPLES already has a part 2 answer".to_string());
+    }
+    let part1 = examples
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("part1: Some(\""))
+        .and_then(|rest| rest.strip_suffix("\"),"))
+        .map(str::to_string);
+
+    let output = source
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("type Output = "))
+        .and_then(|output| output.strip_suffix(';'))
+        .ok_or("no `type Output`")?;
+    let old_test = format!(
+        "        let input = puzzle.parse(TESTCASE).unwrap();\n        assert_eq!(puzzle.part2(&input).unwrap(), {});\n",
+        expected_value(None, output)
+    );
+    if !source.contains(&old_test) {
+        return Err("the part 2 test has been edited".to_string());
+    }
+
+    let testcase = testcase_text(source).ok_or("no TESTCASE constant")?;
+    let part1_example = PartExample {
+        input: Some(testcase.to_string()),
+        answer: part1.clone(),
+    };
+
+    let mut entries = vec![ExampleEntry {
+        input_const: "TESTCASE",
+        part1,
+        part2: None,
+    }];
+    let mut source = source.to_string();
+    let part2_const = if needs_own_testcase(&part1_example, part2) {
+        let testcase_end = testcase_end(&source).ok_or("no TESTCASE constant")?;
+        let input = part2.input.as_deref().unwrap_or("");
+        source.insert_str(
+            testcase_end,
+            &format!("\n{}", testcase_const("TESTCASE2", input)),
+        );
+        entries.push(ExampleEntry {
+            input_const: "TESTCASE2",
+            part1: None,
+            part2: Some(answer.to_string()),
+        });
+        "TESTCASE2"
+    } else {
+        entries[0].part2 = Some(answer.to_string());
+        "TESTCASE"
+    };
+
+    let source = source.replacen(examples, &examples_const(&entries), 1);
+    let new_test = format!(
+        "        let input = puzzle.parse({}).unwrap();\n        assert_eq!(puzzle.part2(&input).unwrap(), {});\n",
+        part2_const,
+        expected_value(Some(answer), output)
+    );
+
+    Ok(source.replacen(&old_test, &new_test, 1))
+}
+
+fn needs_own_testcase(part1: &PartExample, part2: &PartExample) -> bool {
+    match (&part1.input, &part2.input) {
+        (Some(input1), Some(input2)) => trim_input(input1) != trim_input(input2),
+        (None, Some(_)) => true,
+        _ => false,
+    }
+}
+
+fn trim_input(input: &str) -> &str {
+    input.trim_end_matches('\n')
+}
+
+/// `const NAME: &str = r"...";`, using as many `#`s as the input needs.
+fn testcase_const(name: &str, input: &str) -> String {
+    let input = trim_input(input);
+    let mut hashes = String::new();
+    while input.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+
+    format!(
+        "const {}: &str = r{hashes}\"{}\"{hashes};\n",
+        name,
+        input,
+        hashes = hashes
+    )
+}
+
+/// Byte offsets of the `TESTCASE` literal's contents (start and end) and of
+/// the end of the declaration.
+fn testcase_bounds(source: &str) -> Option<(usize, usize, usize)> {
+    let decl = "const TESTCASE: &str = r";
+    let start = source.find(decl)? + decl.len();
+    let hashes = source[start..].chars().take_while(|&c| c == '#').count();
+    let content_start = start + hashes + 1;
+    let terminator = format!("\"{};", "#".repeat(hashes));
+    let content_end = content_start + source[content_start..].find(&terminator)?;
+
+    Some((content_start, content_end, content_end + terminator.len()))
+}
+
+fn testcase_text(source: &str) -> Option<&str> {
+    let (start, end, _) = testcase_bounds(source)?;
+    Some(&source[start..end])
+}
+
+/// Just past the newline that ends the `TESTCASE` declaration.
+fn testcase_end(source: &str) -> Option<usize> {
+    let (_, _, end) = testcase_bounds(source)?;
+    Some(end + source[end..].find('\n')? + 1)
+}
+
+fn examples_const(entries: &[ExampleEntry]) -> String {
+    fn answer(answer: &Option<String>) -> String {
+        match answer {
+            Some(answer) => format!("Some({:?})", answer),
+            None => "None".to_string(),
+        }
+    }
+
+    if let [entry] = entries {
+        return format!(
+            "    const EXAMPLES: &'static [Example] = &[Example {{\n        input: {},\n        part1: {},\n        part2: {},\n    }}];\n",
+            entry.input_const,
+            answer(&entry.part1),
+            answer(&entry.part2)
+        );
+    }
+
+    let mut text = "    const EXAMPLES: &'static [Example] = &[\n".to_string();
+    for entry in entries {
+        text += &format!(
+            "        Example {{\n            input: {},\n            part1: {},\n            part2: {},\n        }},\n",
+            entry.input_const,
+            answer(&entry.part1),
+            answer(&entry.part2)
+        );
+    }
+    text + "    ];\n"
+}
+
+fn part_test(day: i32, part: u8, input_const: &str, answer: Option<&str>, output: &str) -> String {
+    format!(
+        "    #[test]\n    fn test_puzzle_day{day}_part{part}() {{\n        let puzzle = Day{day};\n\n        let input = puzzle.parse({input}).unwrap();\n        assert_eq!(puzzle.part{part}(&input).unwrap(), {expected});\n    }}\n",
+        day = day,
+        part = part,
+        input = input_const,
+        expected = expected_value(answer, output)
+    )
+}
+
+/// The literal a generated test compares against: the stated answer when it
+/// fits the `output` type, otherwise a placeholder.
+fn expected_value(answer: Option<&str>, output: &str) -> String {
+    if output == "String" {
+        return format!("{:?}", answer.unwrap_or(""));
+    }
+
+    answer
+        .and_then(|answer| answer.parse::<i128>().ok())
+        .filter(|value| *value >= 0 || output.starts_with('i'))
+        .unwrap_or(0)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(day: i32, examples: &PuzzleExamples) -> String {
+        let template = builtin_template(DEFAULT_TEMPLATE).unwrap();
+        solution_module(template, day, "i64", examples).unwrap()
+    }
+
+    fn example(input: Option<&str>, answer: Option<&str>) -> Option<PartExample> {
+        Some(PartExample {
+            input: input.map(str::to_string),
+            answer: answer.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_solution_module_without_examples() {
+        let source = module(12, &PuzzleExamples::default());
+
+        assert!(source.contains("pub struct Day12;\n"));
+        assert!(source.contains("const TESTCASE: &str = r\"\";\n"));
+        assert!(source.contains("        part1: None,\n        part2: None,\n    }];\n"));
+        assert!(source.contains("    type Output = i64;\n"));
+        assert!(source.contains("assert_eq!(puzzle.part1(&input).unwrap(), 0);"));
+        assert!(source.ends_with("    }\n}\n"));
+    }
+
+    #[test]
+    fn test_solution_module_with_examples() {
+        let examples = PuzzleExamples {
+            part1: example(Some("say \"hi\"\n1 2\n"), Some("13")),
+            part2: example(None, Some("43")),
+        };
+        let source = module(4, &examples);
+
+        assert!(source.contains("const TESTCASE: &str = r#\"say \"hi\"\n1 2\"#;\n"));
+        assert!(source.contains("        part1: Some(\"13\"),\n        part2: Some(\"43\"),\n"));
+        assert!(source.contains("assert_eq!(puzzle.part1(&input).unwrap(), 13);"));
+        assert!(source.contains("assert_eq!(puzzle.part2(&input).unwrap(), 43);"));
+        assert_eq!(testcase_text(&source), Some("say \"hi\"\n1 2"));
+    }
+
+    #[test]
+    fn test_add_part2_example_same_input() {
+        let part1 = PuzzleExamples {
+            part1: example(Some("1 2\n"), Some("3")),
+            part2: None,
+        };
+        let source = module(5, &part1);
+        let updated = add_part2_example(&source, &example(None, Some("7")).unwrap()).unwrap();
+
+        assert_eq!(
+            updated,
+            module(
+                5,
+                &PuzzleExamples {
+                    part2: example(None, Some("7")),
+                    ..part1
+                }
+            )
+        );
+        assert!(add_part2_example(&updated, &example(None, Some("7")).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_add_part2_example_new_input() {
+        let part1 = PuzzleExamples {
+            part1: example(Some("1 2\n"), Some("3")),
+            part2: None,
+        };
+        let part2 = example(Some("4 5\n"), Some("bad")).unwrap();
+        let updated = add_part2_example(&module(5, &part1), &part2).unwrap();
+
+        assert_eq!(
+            updated,
+            module(
+                5,
+                &PuzzleExamples {
+                    part2: Some(part2),
+                    ..part1
+                }
+            )
+        );
+        assert!(updated.contains("const TESTCASE2: &str = r\"4 5\";\n"));
+        assert!(updated.contains("puzzle.parse(TESTCASE2)"));
+    }
+
+    #[test]
+    fn test_new_day_plan() {
+        let mod_rs = "pub mod day1;\n\npub static PUZZLES: &[(i32, &dyn Solver)] = &[\n    (1, &day1::Day1),\n];\n";
+        let plan = |day, exists, force| {
+            NewDay::plan(
+                day,
+                Path::new(&format!("src/puzzles/day{}.rs", day)),
+                exists,
+                force,
+                Path::new("src/puzzles/mod.rs"),
+                mod_rs,
+            )
+        };
+
+        assert_eq!(
+            plan(2, false, false).unwrap().dry_run().unwrap(),
+            [
+                "Would create src/puzzles/day2.rs",
+                "Would modify src/puzzles/mod.rs"
+            ]
+        );
+        assert_eq!(
+            plan(1, true, false).unwrap_err(),
+            "src/puzzles/day1.rs already exists (use --force to regenerate it)"
+        );
+
+        let forced = plan(1, true, true).unwrap();
+        assert_eq!(forced.registered, Ok(None));
+        assert_eq!(
+            forced.dry_run().unwrap(),
+            [
+                "Would overwrite src/puzzles/day1.rs",
+                "Would leave src/puzzles/mod.rs unchanged"
+            ]
+        );
+
+        let unregistrable = NewDay::plan(
+            2,
+            Path::new("day2.rs"),
+            false,
+            false,
+            Path::new("mod.rs"),
+            "pub mod day1;\n",
+        )
+        .unwrap();
+        assert!(unregistrable
+            .dry_run()
+            .unwrap_err()
+            .starts_with("cannot register day 2 in mod.rs: no `PUZZLES` table;"));
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "Day{{day}}: {{ output }}}",
+                &[("day", "3"), ("output", "i64")]
+            )
+            .unwrap(),
+            "Day3: i64}"
+        );
+        assert!(render("{{day}} {{dya}}", &[("day", "3")]).is_err());
+        assert!(render("{{day", &[("day", "3")]).is_err());
+    }
+
+    #[test]
+    fn test_builtin_templates() {
+        let examples = PuzzleExamples {
+            part1: example(Some("ab\ncd\n"), Some("xy")),
+            part2: None,
+        };
+
+        for (name, template) in BUILTIN_TEMPLATES {
+            let source = solution_module(template, 7, "String", &examples)
+                .unwrap_or_else(|e| panic!("template {}: {}", name, e));
+            assert!(source.contains("pub struct Day7;\n"), "template {}", name);
+            assert!(source.contains("    type Output = String;\n"));
+            assert!(source.contains("assert_eq!(puzzle.part1(&input).unwrap(), \"xy\");"));
+        }
+
+        let updated = add_part2_example(
+            &solution_module(BUILTIN_TEMPLATES[0].1, 7, "String", &examples).unwrap(),
+            &example(None, Some("zz")).unwrap(),
+        )
+        .unwrap();
+        assert!(updated.contains("assert_eq!(puzzle.part2(&input).unwrap(), \"zz\");"));
+    }
+}
@@ -0,0 +1,286 @@
+use std::{error::Error, fmt, path::Path};
+
+use crate::{
+    config::{Profile, CONFIG_FILE},
+    files::read_optional,
+};
+
+/// File holding the session cookie, in the project root.
+pub const COOKIE_FILE: &str = ".cookie";
+
+/// Where a [`Session`] was loaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    CookieFile,
+    ConfigFile,
+    /// The environment variable overriding a named profile's session.
+    ProfileEnv(String),
+    /// The `[profiles.<name>]` table of the config file.
+    Profile(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Env => f.write_str("the AOC_SESSION environment variable"),
+            Source::CookieFile => f.write_str(COOKIE_FILE),
+            Source::ConfigFile => f.write_str(CONFIG_FILE),
+            Source::ProfileEnv(var) => write!(f, "the {} environment variable", var),
+            Source::Profile(name) => write!(f, "[profiles.{}] in {}", name, CONFIG_FILE),
+        }
+    }
+}
+
+/// An adventofcode.com session token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    token: String,
+    pub source: Source,
+}
+
+impl Session {
+    /// Accepts the token on its own or as `session=<token>`, the way the
+    /// browser shows it, with surrounding whitespace ignored.
+    pub fn parse(raw: &str, source: Source) -> Result<Session, String> {
+        let raw = raw.trim();
+        let token = raw.strip_prefix("session=").unwrap_or(raw).trim();
+
+        if token.is_empty() {
+            return Err(format!("the session in {} is empty", source));
+        }
+        if !token.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!(
+                "the session in {} is not a session token; copy the value of the `session` cookie",
+                source
+            ));
+        }
+
+        Ok(Session {
+            token: token.to_string(),
+            source,
+        })
+    }
+
+    /// Takes the session from, in order of precedence, `AOC_SESSION`,
+    /// [`COOKIE_FILE`] and the `session` key of the config file.
+    pub fn load(config_session: Option<&str>) -> Result<Session, Box<dyn Error>> {
+        let cookie_file = read_optional(Path::new(COOKIE_FILE))?;
+
+        Session::from_sources(
+            std::env::var("AOC_SESSION").ok().as_deref(),
+            cookie_file.as_deref(),
+            config_session,
+        )
+    }
+
+    /// The session of `profile`: for the default account as
+    /// [`Session::load`], for a named one from `AOC_SESSION_<NAME>` (upper
+    /// case, `-` as `_`) or its `session` key.
+    pub fn for_profile(profile: &Profile) -> Result<Session, Box<dyn Error>> {
+        let Some(name) = &profile.name else {
+            return Session::load(profile.session.as_deref());
+        };
+
+        let var = profile_env_var(name);
+        if let Ok(raw) = std::env::var(&var) {
+            return Ok(Session::parse(&raw, Source::ProfileEnv(var))?);
+        }
+        match &profile.session {
+            Some(raw) => Ok(Session::parse(raw, Source::Profile(name.clone()))?),
+            None => Err(format!(
+                "no session for profile {}: set {} or add `session = \"...\"` to [profiles.{}] in {}",
+                name, var, name, CONFIG_FILE
+            )
+            .into()),
+        }
+    }
+
+    fn from_sources(
+        env: Option<&str>,
+        cookie_file: Option<&str>,
+        config: Option<&str>,
+    ) -> Result<Session, Box<dyn Error>> {
+        let (raw, source) = match (env, cookie_file, config) {
+            (Some(raw), _, _) => (raw, Source::Env),
+            (None, Some(raw), _) => (raw, Source::CookieFile),
+            (None, None, Some(raw)) => (raw, Source::ConfigFile),
+            (None, None, None) => {
+                return Err(format!(
+                    "no session: set AOC_SESSION, write it to {} or add `session = \"...\"` to {}",
+                    COOKIE_FILE, CONFIG_FILE
+                )
+                .into())
+            }
+        };
+
+        Ok(Session::parse(raw, source)?)
+    }
+
+    /// The value of the `Cookie` header to send.
+    pub fn cookie(&self) -> String {
+        format!("session={}", self.token)
+    }
+}
+
+fn profile_env_var(name: &str) -> String {
+    format!(
+        "AOC_SESSION_{}",
+        name.to_ascii_uppercase().replace('-', "_")
+    )
+}
+
+/// A request to the puzzle site that did not succeed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FetchError {
+    SessionExpired,
+    NotUnlocked,
+    Status(u16),
+}
+
+impl FetchError {
+    /// Explains a response that was not `200 OK`, or a `200 OK` page that
+    /// asks to log in. Only the site's own message means a puzzle is locked:
+    /// any other `404` is a wrong URL, year or day. Only a `400` or the
+    /// site's log-in prompt means the session is no good; server errors are
+    /// reported by status.
+    pub fn classify(status: u16, body: &str) -> Option<FetchError> {
+        if body.contains("before it unlocks") {
+            Some(FetchError::NotUnlocked)
+        } else if status == 400 || body.contains("Please log in") {
+            Some(FetchError::SessionExpired)
+        } else if status != 200 {
+            Some(FetchError::Status(status))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::SessionExpired => f.write_str(
+                "session expired or invalid: log in again and update the session cookie",
+            ),
+            FetchError::NotUnlocked => f.write_str("puzzle not unlocked yet"),
+            FetchError::Status(404) => {
+                f.write_str("not found (status 404): check the base URL and year")
+            }
+            FetchError::Status(status) => write!(f, "unexpected status {}", status),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+/// The user name shown in the page header of the event page, if logged in.
+pub fn logged_in_user(html: &str) -> Option<String> {
+    let from = html.find("<div class=\"user\">")? + "<div class=\"user\">".len();
+    let user = &html[from..];
+    let user = &user[..user.find('<').unwrap_or(user.len())];
+
+    Some(user.trim().to_string()).filter(|user| !user.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_parse() {
+        let session = Session::parse("  session=53616c7465\n", Source::CookieFile).unwrap();
+        assert_eq!(session.cookie(), "session=53616c7465");
+
+        let session = Session::parse("53616c7465\n", Source::Env).unwrap();
+        assert_eq!(session.cookie(), "session=53616c7465");
+
+        assert!(Session::parse("session=\n", Source::Env).is_err());
+        assert!(Session::parse("session=abc; _ga=1", Source::Env).is_err());
+    }
+
+    #[test]
+    fn test_session_precedence() {
+        let session = Session::from_sources(Some("aa"), Some("bb"), Some("cc")).unwrap();
+        assert_eq!(
+            (session.cookie(), session.source),
+            ("session=aa".to_string(), Source::Env)
+        );
+
+        let session = Session::from_sources(None, Some("bb"), Some("cc")).unwrap();
+        assert_eq!(session.source, Source::CookieFile);
+
+        let session = Session::from_sources(None, None, Some("session=cc")).unwrap();
+        assert_eq!(
+            (session.cookie(), session.source),
+            ("session=cc".to_string(), Source::ConfigFile)
+        );
+
+        assert!(Session::from_sources(None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_profile_session() {
+        let profile = Profile {
+            name: Some("team-bob".to_string()),
+            session: Some("session=bb".to_string()),
+            input_dir: "puzzle_inputs/team-bob".into(),
+        };
+        let session = Session::for_profile(&profile).unwrap();
+        assert_eq!(
+            (session.cookie(), session.source.to_string()),
+            (
+                "session=bb".to_string(),
+                "[profiles.team-bob] in aoc.toml".to_string()
+            )
+        );
+        assert_eq!(profile_env_var("team-bob"), "AOC_SESSION_TEAM_BOB");
+
+        let err = Session::for_profile(&Profile {
+            session: None,
+            ..profile
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("AOC_SESSION_TEAM_BOB"));
+    }
+
+    #[test]
+    fn test_fetch_error_classify() {
+        assert_eq!(FetchError::classify(200, "1 2 3\n"), None);
+        assert_eq!(
+            FetchError::classify(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            Some(FetchError::SessionExpired)
+        );
+        assert_eq!(
+            FetchError::classify(500, "Internal Server Error"),
+            Some(FetchError::Status(500))
+        );
+        assert_eq!(
+            FetchError::classify(
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!"
+            ),
+            Some(FetchError::NotUnlocked)
+        );
+        assert_eq!(
+            FetchError::classify(404, "404 Not Found"),
+            Some(FetchError::Status(404))
+        );
+        assert_eq!(FetchError::classify(503, ""), Some(FetchError::Status(503)));
+        assert_eq!(
+            FetchError::NotUnlocked.to_string(),
+            "puzzle not unlocked yet"
+        );
+    }
+
+    #[test]
+    fn test_logged_in_user() {
+        let page = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1><nav>...</nav><div class="user">Jane Doe <span class="star-count">22*</span></div></div></header>"#;
+
+        assert_eq!(logged_in_user(page), Some("Jane Doe".to_string()));
+        assert_eq!(logged_in_user("<a href=\"/auth/login\">[Log In]</a>"), None);
+    }
+}
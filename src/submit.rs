@@ -0,0 +1,264 @@
+use std::{error::Error, fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    files::read_optional,
+    html::{between, text},
+};
+
+/// Every answer sent with `aoc2025 submit`, with the site's verdict. One
+/// history per account covers all years, as each [`Submission`] records its
+/// own; see [`crate::config::Profile::submissions_path`].
+pub const SUBMISSIONS_FILE: &str = "submissions.json";
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "verdict")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Submitted too soon after a previous answer; `wait` is the site's own
+    /// wording, e.g. "4m 32s".
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    Unrecognized,
+}
+
+impl Verdict {
+    /// Classifies the HTML page the site returns after a submission.
+    pub fn from_response(html: &str) -> Verdict {
+        let text = response_message(html);
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited {
+                wait: between(&text, "You have ", " left to wait").map(str::to_string),
+            }
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unrecognized
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// The text of the `<article>` holding the site's reply, with tags
+/// stripped. Falls back to the whole page.
+pub fn response_message(html: &str) -> String {
+    let article = between(html, "<article>", "</article>").unwrap_or(html);
+
+    text(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: i32,
+    pub day: i32,
+    pub part: u8,
+    pub answer: String,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+/// What the local history says about an answer before it is submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check<'a> {
+    New,
+    AlreadyCorrect,
+    /// The same answer was rejected before.
+    KnownWrong(&'a Submission),
+    /// A different answer was rejected as too high or too low, and this
+    /// one is on the wrong side of it.
+    OutOfBounds(&'a Submission),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Submissions {
+    pub submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Loads the history at `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Submissions, Box<dyn Error>> {
+        match read_optional(path)? {
+            Some(text) => serde_json::from_str(&text)
+                .map_err(|e| format!("invalid {}: {}", path.display(), e).into()),
+            None => Ok(Submissions::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, submission: Submission) {
+        self.submissions.push(submission);
+    }
+
+    pub fn check(&self, year: i32, day: i32, part: u8, answer: &str) -> Check<'_> {
+        let history = self
+            .submissions
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part);
+
+        let mut out_of_bounds = None;
+        for submission in history {
+            if submission.verdict == Verdict::Correct {
+                return Check::AlreadyCorrect;
+            }
+            if submission.answer == answer && submission.verdict.is_wrong() {
+                return Check::KnownWrong(submission);
+            }
+
+            let (Ok(new), Ok(old)) = (answer.parse::<i128>(), submission.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            let beyond = match submission.verdict {
+                Verdict::TooHigh => new >= old,
+                Verdict::TooLow => new <= old,
+                _ => false,
+            };
+            if beyond {
+                out_of_bounds.get_or_insert(submission);
+            }
+        }
+
+        out_of_bounds.map_or(Check::New, Check::OutOfBounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    fn submission(answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_verdict_from_response() {
+        let cases = [
+            ("That's the right answer! You are <em>one gold star</em> closer.", Verdict::Correct),
+            ("That's not the right answer; your answer is too high. Please wait one minute.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            ("That's not the right answer. If you're stuck, ...", Verdict::Wrong),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.",
+                Verdict::RateLimited { wait: Some("4m 32s".to_string()) },
+            ),
+            ("You don't seem to be solving the right level.  Did you already complete it?", Verdict::WrongLevel),
+            ("Something else entirely", Verdict::Unrecognized),
+        ];
+
+        for (message, verdict) in cases {
+            assert_eq!(
+                Verdict::from_response(&page(message)),
+                verdict,
+                "{}",
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn test_response_message() {
+        assert_eq!(
+            response_message(&page("That's <em>the</em>\n  right answer!")),
+            "That's the right answer!"
+        );
+    }
+
+    #[test]
+    fn test_submissions_check() {
+        let mut store = Submissions::default();
+        assert_eq!(store.check(2025, 1, 1, "50"), Check::New);
+
+        store.record(submission("100", Verdict::TooHigh));
+        store.record(submission("10", Verdict::TooLow));
+        store.record(submission("42", Verdict::Wrong));
+        store.record(submission("55", Verdict::RateLimited { wait: None }));
+
+        assert_eq!(store.check(2025, 1, 1, "50"), Check::New);
+        assert_eq!(store.check(2025, 1, 1, "55"), Check::New);
+        assert_eq!(store.check(2025, 1, 2, "100"), Check::New);
+        assert_eq!(
+            store.check(2025, 1, 1, "42"),
+            Check::KnownWrong(&store.submissions[2])
+        );
+        assert_eq!(
+            store.check(2025, 1, 1, "150"),
+            Check::OutOfBounds(&store.submissions[0])
+        );
+        assert_eq!(
+            store.check(2025, 1, 1, "10"),
+            Check::KnownWrong(&store.submissions[1])
+        );
+        assert_eq!(
+            store.check(2025, 1, 1, "-3"),
+            Check::OutOfBounds(&store.submissions[1])
+        );
+
+        store.record(submission("50", Verdict::Correct));
+        assert_eq!(store.check(2025, 1, 1, "51"), Check::AlreadyCorrect);
+    }
+
+    #[test]
+    fn test_submissions_roundtrip() {
+        let mut store = Submissions::default();
+        store.record(submission(
+            "7",
+            Verdict::RateLimited {
+                wait: Some("1m".to_string()),
+            },
+        ));
+        store.record(submission("8", Verdict::TooLow));
+
+        let json = serde_json::to_string(&store).unwrap();
+        assert!(json.contains("\"verdict\":\"too_low\""));
+
+        let loaded: Submissions = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.submissions, store.submissions);
+    }
+}
@@ -0,0 +1,100 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight UTC-5, which is 05:00 UTC.
+const UNLOCK_HOUR_UTC: i64 = 5;
+
+/// Days from 1970-01-01 to `year`-`month`-`day` in the proleptic Gregorian
+/// calendar (Howard Hinnant's `days_from_civil`).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// When day `day` of the `year` event unlocks.
+pub fn unlock_time(year: i32, day: i32) -> SystemTime {
+    // Any `i32` year is well within `i64` seconds, before or after 1970.
+    let secs = days_from_civil(year as i64, 12, day as i64) * 86400 + UNLOCK_HOUR_UTC * 3600;
+    let offset = Duration::from_secs(secs.unsigned_abs());
+    if secs >= 0 {
+        UNIX_EPOCH + offset
+    } else {
+        UNIX_EPOCH - offset
+    }
+}
+
+/// How long until the puzzle unlocks, or `None` if it already has.
+pub fn time_until_unlock(year: i32, day: i32, now: SystemTime) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(now)
+        .ok()
+        .filter(|left| !left.is_zero())
+}
+
+/// `1d 02:03:04`, or `02:03:04` when less than a day is left. Partial
+/// seconds round up, so the countdown never shows zero early.
+pub fn format_countdown(left: Duration) -> String {
+    let secs = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    );
+
+    match secs / 86400 {
+        0 => clock,
+        days => format!("{}d {}", days, clock),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2025-12-01T05:00:00Z and 2024-12-25T05:00:00Z.
+        assert_eq!(unlock_time(2025, 1), at(1764565200));
+        assert_eq!(unlock_time(2024, 25), at(1735102800));
+        // 1969-12-01T05:00:00Z.
+        assert_eq!(
+            unlock_time(1969, 1),
+            UNIX_EPOCH - Duration::from_secs(31 * 86400 - 5 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_time_until_unlock() {
+        let unlock = 1764565200;
+
+        assert_eq!(
+            time_until_unlock(2025, 1, at(unlock - 90)),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(time_until_unlock(2025, 1, at(unlock)), None);
+        assert_eq!(time_until_unlock(2025, 1, at(unlock + 1)), None);
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(format_countdown(Duration::from_millis(58_200)), "00:00:59");
+        assert_eq!(
+            format_countdown(Duration::from_secs(3 * 3600 + 61)),
+            "03:01:01"
+        );
+        assert_eq!(
+            format_countdown(Duration::from_secs(2 * 86400 + 7200)),
+            "2d 02:00:00"
+        );
+    }
+}
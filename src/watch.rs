@@ -0,0 +1,136 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+/// How often watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Detects changes to a set of files by polling their modification times
+/// and sizes. A file that appears or disappears counts as a change.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    last: Vec<Option<(SystemTime, u64)>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Watcher {
+        let last = snapshot(&paths);
+        Watcher { paths, last }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Whether any file changed since the last call (or since the watcher
+    /// was created).
+    pub fn changed(&mut self) -> bool {
+        let now = snapshot(&self.paths);
+        let changed = now != self.last;
+        self.last = now;
+        changed
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Vec<Option<(SystemTime, u64)>> {
+    paths
+        .iter()
+        .map(|path| {
+            let metadata = fs::metadata(path).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        })
+        .collect()
+}
+
+/// The answer of each solved part in the JSON report of `day`.
+pub fn answers_from_json(json: &str) -> Result<BTreeMap<u8, String>, String> {
+    let records: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|e| format!("invalid report: {}", e))?;
+
+    Ok(records
+        .iter()
+        .filter(|record| record["status"] == "solved")
+        .filter_map(|record| {
+            let part = record["part"].as_u64()? as u8;
+            Some((part, record["answer"].as_str()?.to_string()))
+        })
+        .collect())
+}
+
+/// One line per part, comparing the answers of the latest run with the
+/// previous one.
+pub fn diff_answers(
+    day: i32,
+    previous: Option<&BTreeMap<u8, String>>,
+    latest: &BTreeMap<u8, String>,
+) -> Vec<String> {
+    latest
+        .iter()
+        .map(|(part, answer)| {
+            let change = match previous.and_then(|previous| previous.get(part)) {
+                None => "new".to_string(),
+                Some(old) if old == answer => "unchanged".to_string(),
+                Some(old) => format!("was {}", old),
+            };
+            format!(
+                "Answer of Day {} Part {}:  {}  ({})",
+                day, part, answer, change
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watcher() {
+        let path = std::env::temp_dir().join(format!("aoc2025-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(!watcher.changed());
+
+        fs::write(&path, "1").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::write(&path, "12").unwrap();
+        assert!(watcher.changed());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+
+    #[test]
+    fn test_diff_answers() {
+        let json = r#"[
+            {"day": 1, "part": null, "status": "parsed", "answer": null},
+            {"day": 1, "part": 1, "status": "solved", "answer": "3"},
+            {"day": 1, "part": 2, "status": "solved", "answer": "6"}
+        ]"#;
+        let first = answers_from_json(json).unwrap();
+        assert_eq!(
+            diff_answers(1, None, &first),
+            [
+                "Answer of Day 1 Part 1:  3  (new)",
+                "Answer of Day 1 Part 2:  6  (new)"
+            ]
+        );
+
+        let second = answers_from_json(&json.replace("\"6\"", "\"7\"")).unwrap();
+        assert_eq!(
+            diff_answers(1, Some(&first), &second),
+            [
+                "Answer of Day 1 Part 1:  3  (unchanged)",
+                "Answer of Day 1 Part 2:  7  (was 6)"
+            ]
+        );
+
+        assert!(answers_from_json("error").is_err());
+    }
+}